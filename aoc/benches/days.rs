@@ -0,0 +1,54 @@
+//! days times every day over its checked-in input with the same entry points
+//! as `aoc bench`, printing the mean of each stage in microseconds
+
+// only the timing entry point of every day is used here
+#[allow(dead_code)]
+#[path = "../src/days.rs"]
+mod days;
+
+use common::Timings;
+use days::DAYS;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// ITERATIONS is how many times every day is run
+const ITERATIONS: u32 = 10;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    println!(
+        "{:>3}  {:>11}  {:>11}  {:>11}",
+        "day", "parse µs", "part one µs", "part two µs"
+    );
+    for (index, day) in DAYS.iter().enumerate() {
+        let path = root.join(format!("day_{:02}/src/input", index + 1));
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) => {
+                eprintln!("skipping {}: {}", path.display(), error);
+                continue;
+            }
+        };
+
+        let runs: Result<Vec<Timings>, _> = (0..ITERATIONS).map(|_| (day.time)(&content)).collect();
+        let runs = match runs {
+            Ok(runs) => runs,
+            Err(error) => {
+                eprintln!("skipping day {}: {}", index + 1, error);
+                continue;
+            }
+        };
+        let mean = |stage: fn(&Timings) -> Duration| {
+            let total: Duration = runs.iter().map(stage).sum();
+            (total / ITERATIONS).as_secs_f64() * 1e6
+        };
+        println!(
+            "{:>3}  {:>11.1}  {:>11.1}  {:>11.1}",
+            index + 1,
+            mean(|t| t.parse),
+            mean(|t| t.part_one),
+            mean(|t| t.part_two)
+        );
+    }
+}
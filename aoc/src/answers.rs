@@ -0,0 +1,145 @@
+use crate::DAYS;
+use common::{input, Error, Part};
+use std::fmt;
+use std::path::Path;
+
+/// MANIFEST is the default answers manifest, relative to the repository root
+pub const MANIFEST: &str = "answers.txt";
+
+/// Expectation is one line of the answers manifest: the answer a day's part
+/// must give for an input file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    pub day: usize,
+    pub part: Part,
+    pub filename: String,
+    pub answer: String,
+}
+
+/// Mismatch is an expectation that was not met, either because the solver
+/// gave another answer or because it failed
+#[derive(Debug)]
+pub struct Mismatch {
+    pub expectation: Expectation,
+    pub found: Result<String, String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let e = &self.expectation;
+        match &self.found {
+            Ok(answer) => write!(
+                f,
+                "day {} part {} on {}: expected {}, found {}",
+                e.day, e.part, e.filename, e.answer, answer
+            ),
+            Err(why) => write!(
+                f,
+                "day {} part {} on {}: expected {}, failed with: {}",
+                e.day, e.part, e.filename, e.answer, why
+            ),
+        }
+    }
+}
+
+/// parse_manifest reads `<day> <part> <input file> <answer>` lines, skipping
+/// blank lines and `#` comments
+pub fn parse_manifest(content: &str) -> Result<Vec<Expectation>, Error> {
+    let mut expectations = vec![];
+    for (line_number, line) in input::lines(content) {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 4 {
+            let message = format!("expected 4 fields, found {}", fields.len());
+            return Err(Error::at(line_number, line, line, message));
+        }
+
+        let day = match fields[0].parse() {
+            Ok(day) if day >= 1 && day <= DAYS.len() => day,
+            _ => {
+                let message = format!("unknown day {:?}", fields[0]);
+                return Err(Error::at(line_number, line, fields[0], message));
+            }
+        };
+        let part = match fields[1] {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => {
+                let message = format!("unknown part {:?}", fields[1]);
+                return Err(Error::at(line_number, line, fields[1], message));
+            }
+        };
+
+        expectations.push(Expectation {
+            day,
+            part,
+            filename: fields[2].to_string(),
+            answer: fields[3].to_string(),
+        });
+    }
+
+    Ok(expectations)
+}
+
+/// check runs every expectation, resolving input files against root, and
+/// returns the ones that were not met
+pub fn check(root: &Path, expectations: &[Expectation]) -> Vec<Mismatch> {
+    let mut mismatches = vec![];
+    for expectation in expectations {
+        let filename = root.join(&expectation.filename).display().to_string();
+        let answers = input::read_to_string(&filename).and_then(|content| {
+            (DAYS[expectation.day - 1].solve)(&content, Some(expectation.part))
+        });
+
+        let found = match answers {
+            Ok(answers) => match answers.into_iter().next() {
+                Some(answer) => Ok(answer.value),
+                None => Err(String::from("no answer given")),
+            },
+            Err(why) => Err(why.with_path(&filename).to_string()),
+        };
+        if found.as_ref() != Ok(&expectation.answer) {
+            mismatches.push(Mismatch {
+                expectation: expectation.clone(),
+                found,
+            });
+        }
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_manifest() {
+        let content = "# comment\n\n7 2 day_07/src/input_example_b 126\n";
+        let expectations = super::parse_manifest(content).unwrap();
+        assert_eq!(expectations.len(), 1);
+        assert_eq!(expectations[0].day, 7);
+        assert_eq!(expectations[0].part, Part::Two);
+        assert_eq!(expectations[0].filename, "day_07/src/input_example_b");
+        assert_eq!(expectations[0].answer, "126");
+
+        assert!(super::parse_manifest("7 3 day_07/src/input 1\n").is_err());
+        assert!(super::parse_manifest("7 1 day_07/src/input\n").is_err());
+    }
+
+    #[test]
+    fn test_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let manifest = root.join(super::MANIFEST).display().to_string();
+        let content = common::input::read_to_string(&manifest).unwrap();
+        let expectations = super::parse_manifest(&content).unwrap();
+
+        let mismatches = super::check(&root, &expectations);
+        let report: Vec<String> = mismatches.iter().map(|m| m.to_string()).collect();
+        assert!(mismatches.is_empty(), "\n{}", report.join("\n"));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::format::{json_array, Format};
+use crate::DAYS;
+use common::{Error, Timings};
+use std::time::Duration;
+
+/// Stats summarises how long one stage took over every iteration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_durations(durations: &[Duration]) -> Stats {
+        let total: Duration = durations.iter().sum();
+        Stats {
+            mean: total / durations.len().max(1) as u32,
+            min: durations.iter().min().copied().unwrap_or_default(),
+            max: durations.iter().max().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Report holds the benchmark of a single day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub day: usize,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl Report {
+    fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ]
+    }
+}
+
+/// bench runs a day over the input the given number of times
+pub fn bench(day: usize, content: &str, iterations: u32) -> Result<Report, Error> {
+    let mut runs: Vec<Timings> = vec![];
+    for _ in 0..iterations {
+        runs.push((DAYS[day - 1].time)(content)?);
+    }
+
+    let stats = |stage: fn(&Timings) -> Duration| {
+        let durations: Vec<Duration> = runs.iter().map(stage).collect();
+        Stats::from_durations(&durations)
+    };
+
+    Ok(Report {
+        day,
+        iterations,
+        parse: stats(|t| t.parse),
+        part_one: stats(|t| t.part_one),
+        part_two: stats(|t| t.part_two),
+    })
+}
+
+/// human prints a duration with a unit that keeps it short
+fn human(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// render prints every report in the given format. Tables show the mean of
+/// each stage, while JSON and CSV have one record per day and stage.
+pub fn render(reports: &[Report], format: Format) -> String {
+    let mut lines = vec![];
+    match format {
+        Format::Text => {
+            lines.push(format!(
+                "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+                "day", "parse", "part one", "part two", "total"
+            ));
+            for report in reports {
+                let total = report.parse.mean + report.part_one.mean + report.part_two.mean;
+                lines.push(format!(
+                    "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+                    report.day,
+                    human(report.parse.mean),
+                    human(report.part_one.mean),
+                    human(report.part_two.mean),
+                    human(total)
+                ));
+            }
+        }
+        Format::Json => {
+            let mut records = vec![];
+            for report in reports {
+                for (stage, stats) in report.stages().iter() {
+                    records.push(format!(
+                        "  {{\"day\": {}, \"stage\": \"{}\", \"iterations\": {}, \
+                         \"mean_ns\": {}, \"min_ns\": {}, \"max_ns\": {}}}",
+                        report.day,
+                        stage,
+                        report.iterations,
+                        stats.mean.as_nanos(),
+                        stats.min.as_nanos(),
+                        stats.max.as_nanos()
+                    ));
+                }
+            }
+            lines.push(json_array(&records));
+        }
+        Format::Csv => {
+            lines.push(String::from("day,stage,iterations,mean_ns,min_ns,max_ns"));
+            for report in reports {
+                for (stage, stats) in report.stages().iter() {
+                    lines.push(format!(
+                        "{},{},{},{},{},{}",
+                        report.day,
+                        stage,
+                        report.iterations,
+                        stats.mean.as_nanos(),
+                        stats.min.as_nanos(),
+                        stats.max.as_nanos()
+                    ));
+                }
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, Report, Stats};
+    use std::time::Duration;
+
+    fn report() -> Report {
+        let stats = |nanos| Stats {
+            mean: Duration::from_nanos(nanos),
+            min: Duration::from_nanos(nanos - 1),
+            max: Duration::from_nanos(nanos + 1),
+        };
+        Report {
+            day: 7,
+            iterations: 3,
+            parse: stats(1_500),
+            part_one: stats(200),
+            part_two: stats(3_000_000),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let durations = [3, 1, 2].map(Duration::from_millis);
+        let stats = Stats::from_durations(&durations);
+        assert_eq!(stats.mean, Duration::from_millis(2));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_render() {
+        let table = super::render(&[report()], Format::Text);
+        assert_eq!(
+            table.lines().nth(1).unwrap(),
+            "  7       1.5µs       200ns       3.0ms       3.0ms"
+        );
+
+        let csv = super::render(&[report()], Format::Csv);
+        assert_eq!(
+            csv.lines().collect::<Vec<&str>>(),
+            vec![
+                "day,stage,iterations,mean_ns,min_ns,max_ns",
+                "7,parse,3,1500,1499,1501",
+                "7,part_one,3,200,199,201",
+                "7,part_two,3,3000000,2999999,3000001",
+            ]
+        );
+
+        let json = super::render(&[report()], Format::Json);
+        assert!(json.starts_with("[\n  {\"day\": 7, \"stage\": \"parse\", \"iterations\": 3,"));
+        assert!(json.ends_with("\"max_ns\": 3000001}\n]"));
+    }
+}
@@ -0,0 +1,29 @@
+use common::{solve, time, Answer, Error, Part, Solution, Timings};
+
+/// Day holds the entry points of a single day's solver
+pub struct Day {
+    pub solve: fn(&str, Option<Part>) -> Result<Vec<Answer>, Error>,
+    pub time: fn(&str) -> Result<Timings, Error>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        solve: solve::<S>,
+        time: time::<S>,
+    }
+}
+
+/// DAYS holds every day, in order
+pub const DAYS: [Day; 11] = [
+    day::<day_01::Day01>(),
+    day::<day_02::Day02>(),
+    day::<day_03::Day03>(),
+    day::<day_04::Day04>(),
+    day::<day_05::Day05>(),
+    day::<day_06::Day06>(),
+    day::<day_07::Day07>(),
+    day::<day_08::Day08>(),
+    day::<day_09::Day09>(),
+    day::<day_10::Day10>(),
+    day::<day_11::Day11>(),
+];
@@ -0,0 +1,151 @@
+use crate::format::Format;
+use crate::{parse_day_args, read_input, RunArgs};
+use common::{warn, Error, Solution};
+use day_01::{Combination, Day01, Expense, SubsetSize};
+
+/// Mode is how many entries to look for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// every combination of exactly k entries
+    Exactly(usize),
+    /// a single subset of any size
+    Subset(SubsetSize),
+}
+
+/// ExpensesArgs asks day 1 for entries of the expense report adding up to
+/// an arbitrary target, rather than the pair and triple summing to 2020
+#[derive(Debug, PartialEq)]
+pub struct ExpensesArgs {
+    pub run_args: RunArgs,
+    pub mode: Mode,
+    pub target: i64,
+}
+
+pub fn parse(args: &[String]) -> Result<ExpensesArgs, String> {
+    let mut rest = vec![];
+    let mut k = None;
+    let mut subset = None;
+    let mut target = 2020;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--k" | "-k" => {
+                let value = args.next().ok_or("--k needs a value")?;
+                k = match value.parse() {
+                    Ok(k) if k > 0 => Some(k),
+                    _ => return Err(format!("invalid k: {}", value)),
+                };
+            }
+            "--subset" | "-s" => {
+                let value = args.next().ok_or("--subset needs a value")?;
+                subset = match value.as_str() {
+                    "any" => Some(SubsetSize::Any),
+                    "min" => Some(SubsetSize::Minimal),
+                    _ => return Err(format!("unknown subset size: {}", value)),
+                };
+            }
+            "--target" | "-t" => {
+                let value = args.next().ok_or("--target needs a value")?;
+                target = value
+                    .parse()
+                    .map_err(|_| format!("invalid target: {}", value))?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let mode = match (k, subset) {
+        (Some(_), Some(_)) => return Err(String::from("--k and --subset cannot be combined")),
+        (Some(k), None) => Mode::Exactly(k),
+        (None, Some(size)) => Mode::Subset(size),
+        (None, None) => Mode::Exactly(2),
+    };
+
+    let run_args = parse_day_args("expenses", 1, &rest)?;
+    if run_args.format != Format::Text {
+        return Err(String::from("expenses only prints text"));
+    }
+
+    Ok(ExpensesArgs {
+        run_args,
+        mode,
+        target,
+    })
+}
+
+pub fn run(expenses_args: &ExpensesArgs) -> Result<(), Error> {
+    let (filename, content) = read_input(&expenses_args.run_args, 1)?;
+    let expenses = Day01::parse(&content).map_err(|why| why.with_path(&filename))?;
+    let numbers = day_01::amounts(&expenses);
+
+    let target = expenses_args.target;
+    let k = match expenses_args.mode {
+        Mode::Exactly(k) => k,
+        Mode::Subset(size) => {
+            let subset = day_01::find_subset_sum(&numbers, target, size)
+                .map_err(|why| why.with_path(&filename))?;
+            print_combination(&subset, &expenses, target);
+            return Ok(());
+        }
+    };
+
+    let combinations = day_01::find_k_sums(&numbers, k, target);
+    if combinations.is_empty() {
+        let message = format!("no {} entries add up to {}", k, target);
+        return Err(Error::no_answer(message).with_path(&filename));
+    }
+    if combinations.len() > 1 {
+        warn!(
+            "{} combinations of {} entries add up to {}",
+            combinations.len(),
+            k,
+            target
+        );
+    }
+
+    for combination in &combinations {
+        print_combination(combination, &expenses, target);
+    }
+
+    Ok(())
+}
+
+fn print_combination(combination: &Combination, expenses: &[Expense], target: i64) {
+    println!(
+        "{} = {} at indices {:?}, product {}",
+        combination.describe(expenses),
+        target,
+        combination.indices,
+        combination.product()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mode, SubsetSize};
+    use crate::test_args::args;
+
+    #[test]
+    fn test_parse() {
+        let expenses_args = super::parse(&args(&["--k", "4", "--target", "3000"])).unwrap();
+        assert_eq!(expenses_args.run_args.days, vec![1]);
+        assert_eq!(expenses_args.mode, Mode::Exactly(4));
+        assert_eq!(expenses_args.target, 3000);
+
+        let expenses_args = super::parse(&args(&["--example"])).unwrap();
+        assert_eq!(expenses_args.mode, Mode::Exactly(2));
+        assert_eq!(expenses_args.target, 2020);
+
+        let expenses_args = super::parse(&args(&["--subset", "min", "-t", "-20"])).unwrap();
+        assert_eq!(expenses_args.mode, Mode::Subset(SubsetSize::Minimal));
+        assert_eq!(expenses_args.target, -20);
+
+        assert!(super::parse(&args(&["--k", "0"])).is_err());
+        assert!(super::parse(&args(&["--subset", "all"])).is_err());
+        assert!(super::parse(&args(&["--k", "3", "--subset", "any"])).is_err());
+        assert!(super::parse(&args(&["--target", "x"])).is_err());
+        assert!(super::parse(&args(&["3"])).is_err());
+        assert!(super::parse(&args(&["--part", "1"])).is_err());
+    }
+}
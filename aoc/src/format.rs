@@ -0,0 +1,155 @@
+use common::Answer;
+
+/// Format is how the runner prints what it found
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Format, String> {
+        match value {
+            "text" | "table" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format: {}", value)),
+        }
+    }
+}
+
+/// json_string quotes and escapes a string as a JSON string literal
+pub fn json_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// json_array lays out records, each already rendered as a JSON value, as
+/// a JSON array with one record per line
+pub fn json_array(records: &[String]) -> String {
+    format!("[\n{}\n]", records.join(",\n"))
+}
+
+/// csv_field quotes a CSV field if it would otherwise be misread
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// render_answers prints the answers of every day, given as (day, answer)
+pub fn render_answers(answers: &[(usize, Answer)], format: Format) -> String {
+    let mut lines = vec![];
+    match format {
+        Format::Text => {
+            for (day, answer) in answers {
+                lines.push(format!(
+                    "day {} part {}: {}",
+                    day, answer.part, answer.value
+                ));
+            }
+        }
+        Format::Json => {
+            let records: Vec<String> = answers
+                .iter()
+                .map(|(day, answer)| {
+                    format!(
+                        "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}}}",
+                        day,
+                        answer.part,
+                        json_string(&answer.value),
+                        answer.elapsed.as_nanos()
+                    )
+                })
+                .collect();
+            lines.push(json_array(&records));
+        }
+        Format::Csv => {
+            lines.push(String::from("day,part,answer,elapsed_ns"));
+            for (day, answer) in answers {
+                lines.push(format!(
+                    "{},{},{},{}",
+                    day,
+                    answer.part,
+                    csv_field(&answer.value),
+                    answer.elapsed.as_nanos()
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Format;
+    use common::{Answer, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(super::json_string("2102"), "\"2102\"");
+        assert_eq!(
+            super::json_string("a \"b\"\n\\c\u{1}"),
+            "\"a \\\"b\\\"\\n\\\\c\\u0001\""
+        );
+    }
+
+    #[test]
+    fn test_render_answers() {
+        let answers = vec![
+            (
+                11,
+                Answer {
+                    part: Part::One,
+                    value: String::from("2321"),
+                    elapsed: Duration::from_nanos(1200),
+                },
+            ),
+            (
+                11,
+                Answer {
+                    part: Part::Two,
+                    value: String::from("a,b"),
+                    elapsed: Duration::from_nanos(3400),
+                },
+            ),
+        ];
+
+        assert_eq!(
+            super::render_answers(&answers, Format::Text),
+            "day 11 part 1: 2321\nday 11 part 2: a,b"
+        );
+        assert_eq!(
+            super::render_answers(&answers, Format::Json),
+            [
+                "[",
+                "  {\"day\": 11, \"part\": 1, \"answer\": \"2321\", \"elapsed_ns\": 1200},",
+                "  {\"day\": 11, \"part\": 2, \"answer\": \"a,b\", \"elapsed_ns\": 3400}",
+                "]",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            super::render_answers(&answers, Format::Csv),
+            "day,part,answer,elapsed_ns\n11,1,2321,1200\n11,2,\"a,b\",3400"
+        );
+    }
+}
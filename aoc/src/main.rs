@@ -0,0 +1,523 @@
+mod answers;
+mod bench;
+mod days;
+mod expenses;
+mod format;
+mod passwords;
+mod scaffold;
+mod slopes;
+mod source;
+mod toboggan;
+
+use common::log::{self, Level};
+use common::{input, Answer, Error, Part};
+use days::DAYS;
+use format::Format;
+use source::Source;
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [options]
+       aoc bench <day|all> [--iterations <n>] [options]
+       aoc check [manifest]
+       aoc new [day]
+       aoc expenses [--k <n> | --subset <any|min>] [--target <sum>] [options]
+       aoc passwords [--policy <spec>]... [--report] [--graphemes] [options]
+       aoc slopes [--right <a-b>] [--down <a-b>] [--top <n>] [--legend <spec>] [options]
+       aoc toboggan [--right <n>] [--down <n>] [--tiles] [--style <text|ansi|svg>] [--legend <spec>] [options]
+
+options:
+  --input <path|->         read the input from a file, or - for stdin
+  --example [suffix]       read the checked-in input_example[_suffix]
+  --inputs-dir <dir>       read <dir>/dayNN.txt, also set by AOC_INPUTS_DIR
+  --format <text|json|csv> print answers or timings in this format
+  --verbose, -v            print debug output of the solvers, -vv to trace
+  --quiet                  hide the diagnostic output of the solvers";
+
+/// INPUTS_DIR_VAR names the environment variable that sets the inputs
+/// directory when --inputs-dir is not given
+const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Check(String),
+    New(usize),
+    Expenses(expenses::ExpensesArgs),
+    Passwords(passwords::PasswordsArgs),
+    Slopes(slopes::SlopesArgs),
+    Toboggan(toboggan::TobogganArgs),
+}
+
+#[derive(Debug, PartialEq)]
+struct RunArgs {
+    days: Vec<usize>,
+    part: Option<Part>,
+    source: Source,
+    inputs_dir: Option<String>,
+    format: Format,
+    level: Level,
+}
+
+fn parse_day(value: &str) -> Result<Vec<usize>, String> {
+    if value == "all" {
+        return Ok((1..=DAYS.len()).collect());
+    }
+
+    match value.parse::<usize>() {
+        Ok(day) if day >= 1 && day <= DAYS.len() => Ok(vec![day]),
+        _ => Err(format!("unknown day: {}", value)),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut days = None;
+    let mut part = None;
+    let mut source = Source::Default;
+    let mut inputs_dir = None;
+    let mut format = Format::Text;
+    let mut verbosity = 0;
+    let mut quiet = false;
+
+    let mut args = args.iter().peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let value = args.next().ok_or("--input needs a path, or - for stdin")?;
+                source = Source::Path(value.clone());
+            }
+            "--example" | "-e" => {
+                // the suffix is optional, so only take the next argument
+                // if it cannot be mistaken for a day or a flag
+                let suffix = args
+                    .next_if(|value| {
+                        value.as_str() != "all" && value.chars().all(|c| c.is_ascii_alphabetic())
+                    })
+                    .cloned();
+                source = Source::Example(suffix);
+            }
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(value.clone());
+            }
+            "--format" | "-f" => {
+                let value = args.next().ok_or("--format needs a value")?;
+                format = Format::parse(value)?;
+            }
+            "--quiet" | "-q" => quiet = true,
+            "--verbose" | "-v" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "--part" | "-p" => {
+                let value = args.next().ok_or("--part needs a value")?;
+                part = match value.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    _ => return Err(format!("unknown part: {}", value)),
+                };
+            }
+            _ if days.is_none() => days = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let days = days.ok_or("missing day")?;
+    if days.len() > 1 {
+        if let Source::Path(_) = source {
+            return Err(String::from("--input can only be used with a single day"));
+        }
+    }
+
+    let level = if quiet {
+        Level::Off
+    } else {
+        Level::from_verbosity(verbosity)
+    };
+
+    Ok(RunArgs {
+        days,
+        part,
+        source,
+        inputs_dir,
+        format,
+        level,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchArgs {
+    run_args: RunArgs,
+    iterations: u32,
+}
+
+/// parse_bench_args takes the bench specific flags out and leaves the
+/// choice of days and input to parse_run_args
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut rest = vec![];
+    let mut iterations = 10;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                let value = args.next().ok_or("--iterations needs a value")?;
+                iterations = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid iterations: {}", value)),
+                };
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let run_args = parse_run_args(&rest)?;
+    if run_args.part.is_some() {
+        return Err(String::from(
+            "bench always times both parts, --part is not supported",
+        ));
+    }
+
+    Ok(BenchArgs {
+        run_args,
+        iterations,
+    })
+}
+
+/// parse_day_args hands what a day specific command leaves of its
+/// arguments to parse_run_args, which then only picks the input of that day.
+/// Such commands answer neither part of the puzzle, so --part is rejected.
+fn parse_day_args(command: &str, day: usize, args: &[String]) -> Result<RunArgs, String> {
+    let mut rest = vec![day.to_string()];
+    rest.extend_from_slice(args);
+    let run_args = parse_run_args(&rest)?;
+    if run_args.part.is_some() {
+        return Err(format!("{} does not take --part", command));
+    }
+
+    Ok(run_args)
+}
+
+/// parse_new_args picks the day to scaffold, which can only be the one
+/// after the last day, so that DAYS stays in order
+fn parse_new_args(args: &[String]) -> Result<usize, String> {
+    let next = DAYS.len() + 1;
+    match args {
+        [] => Ok(next),
+        [value] => match value.parse::<usize>() {
+            Ok(day) if day == next => Ok(day),
+            _ => Err(format!("can only create the next day, {}", next)),
+        },
+        _ => Err(String::from("new takes at most one day")),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).map(Command::Run),
+        Some("bench") => parse_bench_args(&args[1..]).map(Command::Bench),
+        Some("check") => match &args[1..] {
+            [] => Ok(Command::Check(answers::MANIFEST.to_string())),
+            [manifest] => Ok(Command::Check(manifest.clone())),
+            _ => Err(String::from("check takes at most one manifest")),
+        },
+        Some("new") => parse_new_args(&args[1..]).map(Command::New),
+        Some("expenses") => expenses::parse(&args[1..]).map(Command::Expenses),
+        Some("passwords") => passwords::parse(&args[1..]).map(Command::Passwords),
+        Some("slopes") => slopes::parse(&args[1..]).map(Command::Slopes),
+        Some("toboggan") => toboggan::parse(&args[1..]).map(Command::Toboggan),
+        Some(command) => Err(format!("unknown command: {}", command)),
+        None => Err(String::from("missing command")),
+    }
+}
+
+/// configure applies the parts of run_args that come from the environment
+/// or affect the whole process
+fn configure(run_args: &mut RunArgs) {
+    if run_args.inputs_dir.is_none() {
+        run_args.inputs_dir = env::var(INPUTS_DIR_VAR).ok();
+    }
+    log::set_level(run_args.level);
+}
+
+/// read_input loads the input of a day from wherever run_args points to
+fn read_input(run_args: &RunArgs, day: usize) -> Result<(String, String), Error> {
+    let filename = run_args
+        .source
+        .filename(day, run_args.inputs_dir.as_deref());
+    let content = input::read_to_string(&filename)?;
+
+    Ok((input::display_name(&filename).to_string(), content))
+}
+
+/// skip_example lets a run over the examples of several days go on, with a
+/// warning, past a day whose example is missing or has no answer, as some
+/// examples are meant for other settings of the puzzle. Any other error is
+/// handed back.
+fn skip_example(run_args: &RunArgs, day: usize, why: Error) -> Result<(), Error> {
+    let skippable = match (&run_args.source, &why) {
+        (Source::Example(_), Error::Io { source, .. }) => source.kind() == io::ErrorKind::NotFound,
+        (Source::Example(_), Error::NoAnswer { .. }) => true,
+        _ => false,
+    };
+    if !skippable || run_args.days.len() < 2 {
+        return Err(why);
+    }
+    common::warn!("skipping day {}: {}", day, why);
+
+    Ok(())
+}
+
+fn run(run_args: &RunArgs) -> Result<(), Error> {
+    let mut all_answers: Vec<(usize, Answer)> = vec![];
+    for day in &run_args.days {
+        let (filename, content) = match read_input(run_args, *day) {
+            Ok(input) => input,
+            Err(why) => {
+                skip_example(run_args, *day, why)?;
+                continue;
+            }
+        };
+
+        let answers = match (DAYS[day - 1].solve)(&content, run_args.part) {
+            Ok(answers) => answers,
+            Err(why) => {
+                skip_example(run_args, *day, why.with_path(&filename))?;
+                continue;
+            }
+        };
+        if run_args.format == Format::Text {
+            // print as we go, so that slow days do not hold earlier ones back
+            println!(
+                "{}",
+                format::render_answers(&answers_of(*day, answers), Format::Text)
+            );
+        } else {
+            all_answers.extend(answers_of(*day, answers));
+        }
+    }
+    if run_args.format != Format::Text {
+        println!("{}", format::render_answers(&all_answers, run_args.format));
+    }
+
+    Ok(())
+}
+
+fn answers_of(day: usize, answers: Vec<Answer>) -> Vec<(usize, Answer)> {
+    answers.into_iter().map(|answer| (day, answer)).collect()
+}
+
+fn bench(bench_args: &BenchArgs) -> Result<(), Error> {
+    let mut reports = vec![];
+    for day in &bench_args.run_args.days {
+        let (filename, content) = match read_input(&bench_args.run_args, *day) {
+            Ok(input) => input,
+            Err(why) => {
+                skip_example(&bench_args.run_args, *day, why)?;
+                continue;
+            }
+        };
+
+        match bench::bench(*day, &content, bench_args.iterations) {
+            Ok(report) => reports.push(report),
+            Err(why) => skip_example(&bench_args.run_args, *day, why.with_path(&filename))?,
+        }
+    }
+    println!("{}", bench::render(&reports, bench_args.run_args.format));
+
+    Ok(())
+}
+
+/// check verifies every answer listed in the manifest, returning how many
+/// did not match
+fn check(manifest: &str) -> Result<usize, Error> {
+    let content = input::read_to_string(manifest)?;
+    let expectations = answers::parse_manifest(&content).map_err(|why| why.with_path(manifest))?;
+
+    // input files are relative to the directory of the manifest
+    let root = Path::new(manifest)
+        .parent()
+        .unwrap_or_else(|| Path::new(""));
+    let mismatches = answers::check(root, &expectations);
+    for mismatch in &mismatches {
+        println!("mismatch: {}", mismatch);
+    }
+    println!(
+        "{} of {} answers match",
+        expectations.len() - mismatches.len(),
+        expectations.len()
+    );
+
+    Ok(mismatches.len())
+}
+
+/// new creates the crate of the given day and wires it into the runner
+fn new(day: usize) -> Result<(), Error> {
+    for path in scaffold::scaffold(Path::new(""), day)? {
+        println!("wrote {}", path);
+    }
+    println!(
+        "add the puzzle input to day_{:02}/src/input and solve away",
+        day
+    );
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(why) => {
+            eprintln!("error: {}", why);
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(mut run_args) => {
+            configure(&mut run_args);
+            run(&run_args)
+        }
+        Command::Bench(mut bench_args) => {
+            configure(&mut bench_args.run_args);
+            bench(&bench_args)
+        }
+        Command::Check(manifest) => match check(&manifest) {
+            Ok(0) => Ok(()),
+            Ok(_) => process::exit(1),
+            Err(why) => Err(why),
+        },
+        Command::New(day) => new(day),
+        Command::Expenses(mut expenses_args) => {
+            configure(&mut expenses_args.run_args);
+            expenses::run(&expenses_args)
+        }
+        Command::Passwords(mut passwords_args) => {
+            configure(&mut passwords_args.run_args);
+            passwords::run(&passwords_args)
+        }
+        Command::Slopes(mut slopes_args) => {
+            configure(&mut slopes_args.run_args);
+            slopes::run(&slopes_args)
+        }
+        Command::Toboggan(mut toboggan_args) => {
+            configure(&mut toboggan_args.run_args);
+            toboggan::run(&toboggan_args)
+        }
+    };
+
+    if let Err(why) = result {
+        eprintln!("{}", why.diagnostic());
+        process::exit(1);
+    }
+}
+
+/// test_args helps the tests of every command build their arguments
+#[cfg(test)]
+mod test_args {
+    /// args turns literals into command line arguments
+    pub fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::Source;
+    use crate::test_args::args;
+
+    #[test]
+    fn test_parse_run_args() {
+        let run_args = super::parse_run_args(&args(&["3", "--part", "2"])).unwrap();
+        assert_eq!(run_args.days, vec![3]);
+        assert_eq!(run_args.part, Some(super::Part::Two));
+
+        let run_args = super::parse_run_args(&args(&["all"])).unwrap();
+        assert_eq!(run_args.days.len(), super::DAYS.len());
+        assert_eq!(run_args.part, None);
+
+        let run_args = super::parse_run_args(&args(&["7", "--input", "-"])).unwrap();
+        assert_eq!(run_args.source, Source::Path(String::from("-")));
+
+        let run_args = super::parse_run_args(&args(&["--example", "b", "10"])).unwrap();
+        assert_eq!(run_args.days, vec![10]);
+        assert_eq!(run_args.source, Source::Example(Some(String::from("b"))));
+
+        let run_args = super::parse_run_args(&args(&["--example", "all"])).unwrap();
+        assert_eq!(run_args.days.len(), super::DAYS.len());
+        assert_eq!(run_args.source, Source::Example(None));
+
+        let run_args = super::parse_run_args(&args(&["2", "--format", "json", "-q"])).unwrap();
+        assert_eq!(run_args.format, super::Format::Json);
+        assert_eq!(run_args.level, super::Level::Off);
+
+        let run_args = super::parse_run_args(&args(&["8", "-v"])).unwrap();
+        assert_eq!(run_args.level, super::Level::Debug);
+
+        let run_args = super::parse_run_args(&args(&["8", "-vv"])).unwrap();
+        assert_eq!(run_args.level, super::Level::Trace);
+
+        let run_args = super::parse_run_args(&args(&["8", "--verbose", "--verbose"])).unwrap();
+        assert_eq!(run_args.level, super::Level::Trace);
+
+        let run_args = super::parse_run_args(&args(&["3", "--inputs-dir", "inputs"])).unwrap();
+        assert_eq!(run_args.inputs_dir, Some(String::from("inputs")));
+
+        assert!(super::parse_run_args(&args(&["all", "--input", "mine"])).is_err());
+        assert!(super::parse_run_args(&args(&["0"])).is_err());
+        assert!(super::parse_run_args(&args(&["26"])).is_err());
+        assert!(super::parse_run_args(&args(&["7", "--part", "3"])).is_err());
+        assert!(super::parse_run_args(&args(&["--part", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_skip_example() {
+        let missing = |kind| super::Error::Io {
+            path: String::from("day_05/src/input_example"),
+            source: super::io::Error::from(kind),
+        };
+        let skip = |run_args: &[&str], why| {
+            let run_args = super::parse_run_args(&args(run_args)).unwrap();
+            super::skip_example(&run_args, 5, why).is_ok()
+        };
+        let not_found = super::io::ErrorKind::NotFound;
+        assert!(skip(&["all", "--example"], missing(not_found)));
+        assert!(skip(
+            &["all", "--example"],
+            super::Error::no_answer("no pair")
+        ));
+        assert!(!skip(&["5", "--example"], missing(not_found)));
+        assert!(!skip(&["all"], missing(not_found)));
+        let denied = super::io::ErrorKind::PermissionDenied;
+        assert!(!skip(&["all", "--example"], missing(denied)));
+        let invalid = super::Error::parse(1, 1, "x", "invalid number");
+        assert!(!skip(&["all", "--example"], invalid));
+    }
+
+    #[test]
+    fn test_parse_new_args() {
+        let next = super::DAYS.len() + 1;
+        assert_eq!(super::parse_new_args(&args(&[])), Ok(next));
+        assert_eq!(super::parse_new_args(&args(&[&next.to_string()])), Ok(next));
+        assert!(super::parse_new_args(&args(&["1"])).is_err());
+        assert!(super::parse_new_args(&args(&[&(next + 1).to_string()])).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let bench_args =
+            super::parse_bench_args(&args(&["all", "-n", "3", "--format", "csv", "--example"]))
+                .unwrap();
+        assert_eq!(bench_args.run_args.days.len(), super::DAYS.len());
+        assert_eq!(bench_args.run_args.source, Source::Example(None));
+        assert_eq!(bench_args.iterations, 3);
+        assert_eq!(bench_args.run_args.format, super::Format::Csv);
+
+        assert!(super::parse_bench_args(&args(&["7", "--iterations", "0"])).is_err());
+        assert!(super::parse_bench_args(&args(&["7", "--format", "xml"])).is_err());
+        assert!(super::parse_bench_args(&args(&["7", "--part", "1"])).is_err());
+    }
+}
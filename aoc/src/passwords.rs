@@ -0,0 +1,220 @@
+use crate::format::{csv_field, json_array, json_string, Format};
+use crate::{parse_day_args, read_input, RunArgs};
+use common::{Error, Solution};
+use day_02::{Day02, Entry, PasswordPolicy, Unit, Verdict};
+
+/// PasswordsArgs audits the day 2 password list under any number of
+/// policies, rather than only the two of the puzzle
+pub struct PasswordsArgs {
+    pub run_args: RunArgs,
+    pub policies: Vec<Box<dyn PasswordPolicy>>,
+    /// list every entry and why it failed rather than only counting them,
+    /// which JSON and CSV always do
+    pub report: bool,
+    /// count and index passwords by graphemes rather than chars
+    pub unit: Unit,
+}
+
+pub fn parse(args: &[String]) -> Result<PasswordsArgs, String> {
+    let mut rest = vec![];
+    let mut policies = vec![];
+    let mut report = false;
+    let mut unit = Unit::Chars;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy" | "-P" => {
+                let value = args.next().ok_or("--policy needs a spec")?;
+                policies.push(day_02::parse_policy(value)?);
+            }
+            "--report" => report = true,
+            "--graphemes" => unit = Unit::Graphemes,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    if policies.is_empty() {
+        policies.push(Box::new(day_02::CountRange));
+        policies.push(Box::new(day_02::ExactlyOnePosition { strict: false }));
+    }
+
+    let run_args = parse_day_args("passwords", 2, &rest)?;
+
+    Ok(PasswordsArgs {
+        run_args,
+        policies,
+        report,
+        unit,
+    })
+}
+
+pub fn run(passwords_args: &PasswordsArgs) -> Result<(), Error> {
+    let (filename, content) = read_input(&passwords_args.run_args, 2)?;
+    let entries = Day02::parse(&content).map_err(|why| why.with_path(&filename))?;
+
+    let format = passwords_args.run_args.format;
+    let unit = passwords_args.unit;
+    if passwords_args.report || format != Format::Text {
+        let verdicts = day_02::audit(&entries, &passwords_args.policies, unit);
+        println!("{}", render_report(&entries, &verdicts, format));
+        if format != Format::Text {
+            return Ok(());
+        }
+    }
+
+    for policy in &passwords_args.policies {
+        let valid = day_02::count_valid(&entries, policy.as_ref(), unit);
+        println!("{}: {} of {} valid", policy, valid, entries.len());
+    }
+    if passwords_args.policies.len() > 1 {
+        let valid = entries
+            .iter()
+            .filter(|entry| {
+                passwords_args
+                    .policies
+                    .iter()
+                    .all(|policy| policy.is_valid(entry, unit))
+            })
+            .count();
+        println!("all: {} of {} valid", valid, entries.len());
+    }
+
+    Ok(())
+}
+
+/// render_report prints the verdicts of an audit. Text lists why each
+/// entry failed, or that it is valid, while JSON and CSV have one record
+/// per entry and policy. Entries are numbered from 1.
+pub fn render_report(entries: &[Entry], verdicts: &[Verdict], format: Format) -> String {
+    let mut lines = vec![];
+    match format {
+        Format::Text => {
+            for (index, entry) in entries.iter().enumerate() {
+                let violations: Vec<&Verdict> = verdicts
+                    .iter()
+                    .filter(|verdict| verdict.entry == index && verdict.violation.is_some())
+                    .collect();
+                if violations.is_empty() {
+                    lines.push(format!("entry {} ({}): valid", index + 1, entry));
+                }
+                for verdict in violations {
+                    lines.push(format!(
+                        "entry {} ({}): {}: {}",
+                        index + 1,
+                        entry,
+                        verdict.policy,
+                        verdict.violation.as_deref().unwrap_or_default()
+                    ));
+                }
+            }
+        }
+        Format::Json => {
+            let records: Vec<String> = verdicts
+                .iter()
+                .map(|verdict| {
+                    let reason = match &verdict.violation {
+                        Some(violation) => json_string(violation),
+                        None => String::from("null"),
+                    };
+                    format!(
+                        "  {{\"entry\": {}, \"text\": {}, \"policy\": {}, \"valid\": {}, \"reason\": {}}}",
+                        verdict.entry + 1,
+                        json_string(&entries[verdict.entry].to_string()),
+                        json_string(&verdict.policy),
+                        verdict.violation.is_none(),
+                        reason
+                    )
+                })
+                .collect();
+            lines.push(json_array(&records));
+        }
+        Format::Csv => {
+            lines.push(String::from("entry,text,policy,valid,reason"));
+            for verdict in verdicts {
+                lines.push(format!(
+                    "{},{},{},{},{}",
+                    verdict.entry + 1,
+                    csv_field(&entries[verdict.entry].to_string()),
+                    csv_field(&verdict.policy),
+                    verdict.violation.is_none(),
+                    csv_field(verdict.violation.as_deref().unwrap_or_default())
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::Format;
+    use crate::test_args::args;
+    use common::Solution;
+    use day_02::{Day02, Unit};
+
+    fn specs(passwords_args: &super::PasswordsArgs) -> Vec<String> {
+        passwords_args
+            .policies
+            .iter()
+            .map(|policy| policy.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let passwords_args = super::parse(&args(&["--example"])).unwrap();
+        assert_eq!(passwords_args.run_args.days, vec![2]);
+        assert_eq!(specs(&passwords_args), vec!["count", "positions"]);
+        assert_eq!(passwords_args.unit, Unit::Chars);
+
+        let passwords_args =
+            super::parse(&args(&["-P", "distinct=3", "--policy", "class=[0-9]"])).unwrap();
+        assert_eq!(specs(&passwords_args), vec!["distinct=3", "class=[0-9]"]);
+
+        let passwords_args = super::parse(&args(&["--report", "-f", "csv"])).unwrap();
+        assert!(passwords_args.report);
+        assert_eq!(passwords_args.run_args.format, Format::Csv);
+
+        let passwords_args = super::parse(&args(&["--graphemes"])).unwrap();
+        assert_eq!(passwords_args.unit, Unit::Graphemes);
+
+        assert!(super::parse(&args(&["--policy", "lowercase"])).is_err());
+        assert!(super::parse(&args(&["--policy"])).is_err());
+        assert!(super::parse(&args(&["--part", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_render_report() {
+        let entries = Day02::parse("1-3 a: abcde\n1-3 b: cd,efg\n").unwrap();
+        let policies = [day_02::parse_policy("count").unwrap()];
+        let verdicts = day_02::audit(&entries, &policies, Unit::Chars);
+
+        let text = super::render_report(&entries, &verdicts, Format::Text);
+        assert_eq!(
+            text.lines().collect::<Vec<&str>>(),
+            vec![
+                "entry 1 (1-3 a: abcde): valid",
+                "entry 2 (1-3 b: cd,efg): count: letter 'b' appears 0 times, allowed 1-3",
+            ]
+        );
+
+        let csv = super::render_report(&entries, &verdicts, Format::Csv);
+        assert_eq!(
+            csv.lines().collect::<Vec<&str>>(),
+            vec![
+                "entry,text,policy,valid,reason",
+                "1,1-3 a: abcde,count,true,",
+                "2,\"1-3 b: cd,efg\",count,false,\"letter 'b' appears 0 times, allowed 1-3\"",
+            ]
+        );
+
+        let json = super::render_report(&entries, &verdicts, Format::Json);
+        assert!(json.contains(
+            "{\"entry\": 1, \"text\": \"1-3 a: abcde\", \"policy\": \"count\", \"valid\": true, \"reason\": null}"
+        ));
+        assert!(json.contains(
+            "\"valid\": false, \"reason\": \"letter 'b' appears 0 times, allowed 1-3\"}"
+        ));
+    }
+}
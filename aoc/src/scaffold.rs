@@ -0,0 +1,290 @@
+use common::{input, Error};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const CARGO_TOML: &str = r#"[package]
+name = "DAY_CRATE"
+version = "0.1.0"
+authors = ["Theodore Keloglou <zf@sirodoht.com>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+/// LIB_RS is the lib.rs of a new day, DAY_STRUCT standing for its struct.
+/// It is kept in a file of its own so that the tests compile it.
+const LIB_RS: &str = include_str!("../templates/lib.rs");
+
+fn crate_name(day: usize) -> String {
+    format!("day_{:02}", day)
+}
+
+fn struct_name(day: usize) -> String {
+    format!("Day{:02}", day)
+}
+
+/// add_member lists the crate of the day in the workspace manifest
+fn add_member(manifest: &str, day: usize) -> Result<String, Error> {
+    let lines: Vec<(usize, &str)> = input::lines(manifest).collect();
+    let start = lines
+        .iter()
+        .position(|(_, line)| line.trim() == "members = [")
+        .ok_or_else(|| Error::parse(1, 1, "", "no workspace members list found"))?;
+    let end = lines[start..]
+        .iter()
+        .position(|(_, line)| line.trim() == "]")
+        .map(|offset| start + offset)
+        .ok_or_else(|| {
+            let (line_number, line) = lines[start];
+            Error::at(
+                line_number,
+                line,
+                line,
+                "workspace members list is not closed",
+            )
+        })?;
+
+    let member = format!("    \"{}\",", crate_name(day));
+    let mut output: Vec<&str> = lines.iter().map(|(_, line)| *line).collect();
+    output.insert(end, &member);
+
+    Ok(output.join("\n") + "\n")
+}
+
+/// add_dependency makes the runner depend on the crate of the day
+fn add_dependency(manifest: &str, day: usize) -> Result<String, Error> {
+    if !manifest.contains("[dependencies]") {
+        return Err(Error::parse(1, 1, "", "no [dependencies] section found"));
+    }
+
+    let name = crate_name(day);
+    let mut output = manifest.trim_end().to_string();
+    output.push_str(&format!("\n{} = {{ path = \"../{}\" }}\n", name, name));
+
+    Ok(output)
+}
+
+/// register_day appends the day to the DAYS array of the runner
+fn register_day(days: &str, day: usize) -> Result<String, Error> {
+    let declaration = format!("pub const DAYS: [Day; {}] = [", day - 1);
+    let lines: Vec<(usize, &str)> = input::lines(days).collect();
+    let start = lines
+        .iter()
+        .position(|(_, line)| *line == declaration)
+        .ok_or_else(|| {
+            let message = format!("expected {:?} in the list of days", declaration);
+            Error::parse(1, 1, "", message)
+        })?;
+    let end = lines[start..]
+        .iter()
+        .position(|(_, line)| *line == "];")
+        .map(|offset| start + offset)
+        .ok_or_else(|| {
+            let (line_number, line) = lines[start];
+            Error::at(line_number, line, line, "list of days is not closed")
+        })?;
+
+    let new_declaration = format!("pub const DAYS: [Day; {}] = [", day);
+    let entry = format!("    day::<{}::{}>(),", crate_name(day), struct_name(day));
+    let mut output: Vec<&str> = lines.iter().map(|(_, line)| *line).collect();
+    output[start] = &new_declaration;
+    output.insert(end, &entry);
+
+    Ok(output.join("\n") + "\n")
+}
+
+fn read(root: &Path, filename: &str) -> Result<(String, String), Error> {
+    let path = root.join(filename).display().to_string();
+    let content = input::read_to_string(&path)?;
+
+    Ok((path, content))
+}
+
+fn write(path: &str, content: &str) -> Result<(), Error> {
+    fs::write(path, content).map_err(|source| Error::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> Error + '_ {
+    move |source| Error::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+/// replace writes content next to path and renames it over path, so that
+/// path holds either its old content or all of the new one
+fn replace(path: &str, content: &str) -> Result<(), Error> {
+    let staged = format!("{}.new", path);
+    write(&staged, content)?;
+    fs::rename(&staged, path).map_err(|source| {
+        let _ = fs::remove_file(&staged);
+        Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    })
+}
+
+/// write_crate writes the files of a crate into dir, which must not exist
+/// yet and is removed again if a file cannot be written
+fn write_crate(dir: &Path, files: &[(&str, String)]) -> Result<(), Error> {
+    fs::create_dir(dir).map_err(io_error(dir))?;
+    let written = files.iter().try_for_each(|(filename, content)| {
+        let path = dir.join(filename);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error(parent))?;
+        }
+        write(&path.display().to_string(), content)
+    });
+    if written.is_err() {
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    written
+}
+
+/// scaffold creates the crate of a new day under root and wires it into the
+/// workspace and the runner, returning the files it created or changed.
+/// Every change is worked out before anything is written. The crate is
+/// written aside and moved into place whole, then the files of the runner
+/// and the workspace are replaced one at a time, the workspace Cargo.toml
+/// last. Should any step fail, the steps before it are undone.
+pub fn scaffold(root: &Path, day: usize) -> Result<Vec<String>, Error> {
+    let name = crate_name(day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::Io {
+            path: dir.display().to_string(),
+            source: io::Error::new(io::ErrorKind::AlreadyExists, "directory already exists"),
+        });
+    }
+
+    // applied in reverse, so that the workspace is edited last
+    let mut edits = vec![];
+    let (path, content) = read(root, "Cargo.toml")?;
+    let edited = add_member(&content, day).map_err(|why| why.with_path(&path))?;
+    edits.push((path, edited, content));
+    let (path, content) = read(root, "aoc/Cargo.toml")?;
+    let edited = add_dependency(&content, day).map_err(|why| why.with_path(&path))?;
+    edits.push((path, edited, content));
+    let (path, content) = read(root, "aoc/src/days.rs")?;
+    let edited = register_day(&content, day).map_err(|why| why.with_path(&path))?;
+    edits.push((path, edited, content));
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML.replace("DAY_CRATE", &name)),
+        (
+            "src/lib.rs",
+            LIB_RS.replace("DAY_STRUCT", &struct_name(day)),
+        ),
+        ("src/input_example", String::new()),
+    ];
+    let staging = root.join(format!(".{}.new", name));
+    write_crate(&staging, &files)?;
+    fs::rename(&staging, &dir).map_err(|source| {
+        let _ = fs::remove_dir_all(&staging);
+        io_error(&dir)(source)
+    })?;
+
+    let mut replaced: Vec<(&str, &str)> = vec![];
+    for (path, edited, original) in edits.iter().rev() {
+        if let Err(error) = replace(path, edited) {
+            for (path, original) in replaced {
+                let _ = replace(path, original);
+            }
+            let _ = fs::remove_dir_all(&dir);
+            return Err(error);
+        }
+        replaced.push((path, original));
+    }
+
+    let mut changed: Vec<String> = edits.into_iter().map(|(path, _, _)| path).collect();
+    for (filename, _) in &files {
+        changed.push(dir.join(filename).display().to_string());
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    // the template must build against the current Solution trait
+    #[allow(dead_code, non_camel_case_types)]
+    mod template {
+        include!("../templates/lib.rs");
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n]\n";
+        assert_eq!(
+            super::add_member(manifest, 2).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n    \"day_02\",\n]\n"
+        );
+        assert!(super::add_member("[workspace]\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\nday_01 = { path = \"../day_01\" }\n";
+        assert_eq!(
+            super::add_dependency(manifest, 12).unwrap(),
+            "[dependencies]\nday_01 = { path = \"../day_01\" }\n\
+             day_12 = { path = \"../day_12\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = "pub const DAYS: [Day; 1] = [\n    day::<day_01::Day01>(),\n];\n";
+        assert_eq!(
+            super::register_day(days, 2).unwrap(),
+            "pub const DAYS: [Day; 2] = [\n    day::<day_01::Day01>(),\n    \
+             day::<day_02::Day02>(),\n];\n"
+        );
+        assert!(super::register_day(days, 3).is_err());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        use std::fs;
+
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n]\n";
+        let runner = "[dependencies]\nday_01 = { path = \"../day_01\" }\n";
+        let days = "pub const DAYS: [Day; 1] = [\n    day::<day_01::Day01>(),\n];\n";
+        fs::write(root.join("Cargo.toml"), workspace).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), runner).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), days).unwrap();
+        let read = |filename: &str| fs::read_to_string(root.join(filename)).unwrap();
+
+        // the workspace manifest cannot be replaced, and what was written
+        // before that is undone
+        fs::create_dir(root.join("Cargo.toml.new")).unwrap();
+        assert!(super::scaffold(&root, 2).is_err());
+        assert_eq!(read("Cargo.toml"), workspace);
+        assert_eq!(read("aoc/Cargo.toml"), runner);
+        assert_eq!(read("aoc/src/days.rs"), days);
+        assert!(!root.join("day_02").exists());
+        assert!(!root.join(".day_02.new").exists());
+        fs::remove_dir(root.join("Cargo.toml.new")).unwrap();
+
+        let changed = super::scaffold(&root, 2).unwrap();
+        assert_eq!(changed.len(), 6);
+        assert!(read("Cargo.toml").contains("\"day_02\""));
+        assert!(read("aoc/src/days.rs").contains("day_02::Day02"));
+        assert!(read("day_02/src/lib.rs").contains("pub struct Day02;"));
+        assert!(super::scaffold(&root, 2).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
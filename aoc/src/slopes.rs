@@ -0,0 +1,230 @@
+use crate::format::{json_array, Format};
+use crate::{parse_day_args, read_input, RunArgs};
+use common::Error;
+use day_03::{Legend, Ranked};
+use std::ops::RangeInclusive;
+
+/// SlopesArgs searches the day 3 map for the best and worst trajectories
+/// within bounds, rather than only trying the five slopes of the puzzle
+#[derive(Debug, PartialEq)]
+pub struct SlopesArgs {
+    pub run_args: RunArgs,
+    pub rights: RangeInclusive<usize>,
+    pub downs: RangeInclusive<usize>,
+    /// how many rows of the ranking to print, all of them if None
+    pub top: Option<usize>,
+    pub legend: Legend,
+}
+
+/// parse_bounds reads either a single value or an inclusive range like
+/// `1-7`
+fn parse_bounds(flag: &str, value: &str) -> Result<RangeInclusive<usize>, String> {
+    let invalid = || format!("invalid {} bounds: {}", flag, value);
+    let (first, last) = value.split_once('-').unwrap_or((value, value));
+    let first: usize = first.parse().map_err(|_| invalid())?;
+    let last: usize = last.parse().map_err(|_| invalid())?;
+    if first > last {
+        return Err(invalid());
+    }
+
+    Ok(first..=last)
+}
+
+pub fn parse(args: &[String]) -> Result<SlopesArgs, String> {
+    let mut rest = vec![];
+    let mut rights = 1..=7;
+    let mut downs = 1..=2;
+    let mut top = None;
+    let mut legend = Legend::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--right" | "-r" => {
+                let value = args.next().ok_or("--right needs bounds")?;
+                rights = parse_bounds("--right", value)?;
+            }
+            "--down" | "-d" => {
+                let value = args.next().ok_or("--down needs bounds")?;
+                downs = parse_bounds("--down", value)?;
+                if *downs.start() == 0 {
+                    return Err(String::from("--down must be at least 1"));
+                }
+            }
+            "--top" | "-n" => {
+                let value = args.next().ok_or("--top needs a value")?;
+                top = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid top: {}", value)),
+                };
+            }
+            "--legend" | "-l" => {
+                let value = args.next().ok_or("--legend needs a value")?;
+                legend = Legend::parse(value)?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let run_args = parse_day_args("slopes", 3, &rest)?;
+
+    Ok(SlopesArgs {
+        run_args,
+        rights,
+        downs,
+        top,
+        legend,
+    })
+}
+
+pub fn run(slopes_args: &SlopesArgs) -> Result<(), Error> {
+    let (filename, content) = read_input(&slopes_args.run_args, 3)?;
+    let legend = &slopes_args.legend;
+    let map = legend
+        .read_map(&content)
+        .map_err(|why| why.with_path(&filename))?;
+
+    let ranked = day_03::rank_slopes(
+        &map,
+        legend,
+        slopes_args.rights.clone(),
+        slopes_args.downs.clone(),
+    );
+    println!(
+        "{}",
+        render(&ranked, slopes_args.top, slopes_args.run_args.format)
+    );
+
+    Ok(())
+}
+
+/// render prints a ranking, preceded in text by the cheapest and the
+/// costliest slopes, the first of them when several tie. Only the first top
+/// rows of the table are kept. With the default legend the cost is the
+/// number of trees.
+pub fn render(ranked: &[Ranked], top: Option<usize>, format: Format) -> String {
+    let rows = &ranked[..top.unwrap_or(ranked.len()).min(ranked.len())];
+    let mut lines = vec![];
+    match format {
+        Format::Text => {
+            let (fewest, most) = day_03::extremes(ranked);
+            for (label, slopes) in &[("fewest", fewest), ("most", most)] {
+                if let Some(r) = slopes.first() {
+                    let mut line = format!(
+                        "{}: cost {} at right {}, down {}",
+                        label, r.collisions, r.slope.right, r.slope.down
+                    );
+                    match slopes.len() {
+                        1 => {}
+                        2 => line.push_str(", and 1 other slope"),
+                        n => line.push_str(&format!(", and {} other slopes", n - 1)),
+                    }
+                    lines.push(line);
+                }
+            }
+            lines.push(format!(
+                "{:>4}  {:>5}  {:>4}  {:>5}",
+                "rank", "right", "down", "cost"
+            ));
+            for r in rows {
+                lines.push(format!(
+                    "{:>4}  {:>5}  {:>4}  {:>5}",
+                    r.rank, r.slope.right, r.slope.down, r.collisions
+                ));
+            }
+        }
+        Format::Json => {
+            let records: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "  {{\"rank\": {}, \"right\": {}, \"down\": {}, \"cost\": {}}}",
+                        r.rank, r.slope.right, r.slope.down, r.collisions
+                    )
+                })
+                .collect();
+            lines.push(json_array(&records));
+        }
+        Format::Csv => {
+            lines.push(String::from("rank,right,down,cost"));
+            for r in rows {
+                lines.push(format!(
+                    "{},{},{},{}",
+                    r.rank, r.slope.right, r.slope.down, r.collisions
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::Format;
+    use crate::test_args::args;
+    use day_03::Legend;
+
+    #[test]
+    fn test_parse() {
+        let slopes_args = super::parse(&args(&["--example"])).unwrap();
+        assert_eq!(slopes_args.run_args.days, vec![3]);
+        assert_eq!(slopes_args.rights, 1..=7);
+        assert_eq!(slopes_args.downs, 1..=2);
+        assert_eq!(slopes_args.top, None);
+        assert_eq!(slopes_args.legend, Legend::default());
+
+        let slopes_args =
+            super::parse(&args(&["--right", "0-30", "-d", "3", "--top", "5"])).unwrap();
+        assert_eq!(slopes_args.rights, 0..=30);
+        assert_eq!(slopes_args.downs, 3..=3);
+        assert_eq!(slopes_args.top, Some(5));
+
+        let slopes_args = super::parse(&args(&["--legend", ".=open:0,#=tree:1,^=rock:5"])).unwrap();
+        assert_eq!(slopes_args.legend.terrains.len(), 3);
+
+        assert!(super::parse(&args(&["--right", "7-1"])).is_err());
+        assert!(super::parse(&args(&["--right", "a-3"])).is_err());
+        assert!(super::parse(&args(&["--down", "0-2"])).is_err());
+        assert!(super::parse(&args(&["--top", "0"])).is_err());
+        assert!(super::parse(&args(&["--legend", ".=open"])).is_err());
+        assert!(super::parse(&args(&["--part", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let legend = Legend::default();
+        let map = day_03::read_map(include_str!("../../day_03/src/input_example")).unwrap();
+        let ranked = day_03::rank_slopes(&map, &legend, 1..=7, 1..=2);
+
+        let text = super::render(&ranked, Some(2), Format::Text);
+        assert_eq!(
+            text.lines().collect::<Vec<&str>>(),
+            vec![
+                "fewest: cost 0 at right 5, down 2",
+                "most: cost 7 at right 3, down 1",
+                "rank  right  down   cost",
+                "   1      5     2      0",
+                "   2      2     1      1",
+            ]
+        );
+
+        let ranked = day_03::rank_slopes(&map, &legend, 2..=2, 1..=2);
+        let text = super::render(&ranked, None, Format::Text);
+        assert_eq!(
+            text.lines().next(),
+            Some("fewest: cost 1 at right 2, down 1, and 1 other slope")
+        );
+
+        let ranked = day_03::rank_slopes(&map, &legend, 1..=7, 1..=2);
+        let csv = super::render(&ranked, None, Format::Csv);
+        assert_eq!(csv.lines().count(), 15);
+        assert_eq!(csv.lines().last(), Some("14,3,1,7"));
+
+        let json = super::render(&ranked, Some(1), Format::Json);
+        assert_eq!(
+            json,
+            "[\n  {\"rank\": 1, \"right\": 5, \"down\": 2, \"cost\": 0}\n]"
+        );
+    }
+}
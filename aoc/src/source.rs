@@ -0,0 +1,59 @@
+use std::path::Path;
+
+/// Source is where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    /// the day's own input, from the inputs directory if one is configured
+    Default,
+    /// an explicit file, or "-" for the standard input
+    Path(String),
+    /// an example input checked in next to the day's input, with an optional
+    /// suffix such as "b" for input_example_b
+    Example(Option<String>),
+}
+
+impl Source {
+    /// filename resolves the file to read for the given day
+    pub fn filename(&self, day: usize, inputs_dir: Option<&str>) -> String {
+        match self {
+            Source::Default => match inputs_dir {
+                Some(dir) => Path::new(dir)
+                    .join(format!("day{:02}.txt", day))
+                    .display()
+                    .to_string(),
+                None => format!("day_{:02}/src/input", day),
+            },
+            Source::Path(path) => path.clone(),
+            Source::Example(None) => format!("day_{:02}/src/input_example", day),
+            Source::Example(Some(suffix)) => {
+                format!("day_{:02}/src/input_example_{}", day, suffix)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+
+    #[test]
+    fn test_filename() {
+        assert_eq!(Source::Default.filename(7, None), "day_07/src/input");
+        assert_eq!(
+            Source::Default.filename(7, Some("inputs")),
+            "inputs/day07.txt"
+        );
+        assert_eq!(
+            Source::Path(String::from("-")).filename(7, Some("inputs")),
+            "-"
+        );
+        assert_eq!(
+            Source::Example(None).filename(10, None),
+            "day_10/src/input_example"
+        );
+        assert_eq!(
+            Source::Example(Some(String::from("b"))).filename(10, None),
+            "day_10/src/input_example_b"
+        );
+    }
+}
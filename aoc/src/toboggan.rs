@@ -0,0 +1,122 @@
+use crate::format::Format;
+use crate::{parse_day_args, read_input, RunArgs};
+use common::Error;
+use day_03::{Legend, Slope, Style};
+
+/// TobogganArgs draws the path of the sled down one slope of the day 3 map
+#[derive(Debug, PartialEq)]
+pub struct TobogganArgs {
+    pub run_args: RunArgs,
+    pub slope: Slope,
+    /// lay the copies of the map the sled crossed side by side
+    pub tiles: bool,
+    pub style: Style,
+    pub legend: Legend,
+}
+
+fn parse_style(value: &str) -> Result<Style, String> {
+    match value {
+        "text" => Ok(Style::Text),
+        "ansi" => Ok(Style::Ansi),
+        "svg" => Ok(Style::Svg),
+        _ => Err(format!("unknown style: {}", value)),
+    }
+}
+
+pub fn parse(args: &[String]) -> Result<TobogganArgs, String> {
+    let mut rest = vec![];
+    let mut slope = Slope { right: 3, down: 1 };
+    let mut tiles = false;
+    let mut style = Style::Text;
+    let mut legend = Legend::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--right" | "-r" => {
+                let value = args.next().ok_or("--right needs a value")?;
+                slope.right = value
+                    .parse()
+                    .map_err(|_| format!("invalid right: {}", value))?;
+            }
+            "--down" | "-d" => {
+                let value = args.next().ok_or("--down needs a value")?;
+                slope.down = match value.parse() {
+                    Ok(down) if down > 0 => down,
+                    _ => return Err(format!("invalid down: {}", value)),
+                };
+            }
+            "--tiles" => tiles = true,
+            "--style" | "-s" => {
+                let value = args.next().ok_or("--style needs a value")?;
+                style = parse_style(value)?;
+            }
+            "--legend" | "-l" => {
+                let value = args.next().ok_or("--legend needs a value")?;
+                legend = Legend::parse(value)?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let run_args = parse_day_args("toboggan", 3, &rest)?;
+    if run_args.format != Format::Text {
+        return Err(String::from("toboggan draws a map, pick one with --style"));
+    }
+
+    Ok(TobogganArgs {
+        run_args,
+        slope,
+        tiles,
+        style,
+        legend,
+    })
+}
+
+pub fn run(toboggan_args: &TobogganArgs) -> Result<(), Error> {
+    let (filename, content) = read_input(&toboggan_args.run_args, 3)?;
+    let legend = &toboggan_args.legend;
+    let map = legend
+        .read_map(&content)
+        .map_err(|why| why.with_path(&filename))?;
+
+    let slope = toboggan_args.slope;
+    let overlay = day_03::overlay(&map, legend, slope, toboggan_args.tiles);
+    println!("{}", overlay.export(toboggan_args.style));
+    if toboggan_args.style != Style::Svg {
+        let collisions = day_03::get_tree_collisions(&map, legend, slope.right, slope.down);
+        println!("{}", collisions.describe(legend));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Legend, Slope, Style};
+    use crate::test_args::args;
+
+    #[test]
+    fn test_parse() {
+        let toboggan_args = super::parse(&args(&["--example"])).unwrap();
+        assert_eq!(toboggan_args.run_args.days, vec![3]);
+        assert_eq!(toboggan_args.slope, Slope { right: 3, down: 1 });
+        assert!(!toboggan_args.tiles);
+        assert_eq!(toboggan_args.style, Style::Text);
+        assert_eq!(toboggan_args.legend, Legend::default());
+
+        let toboggan_args = super::parse(&args(&[
+            "-r", "1", "--down", "2", "--tiles", "--style", "svg",
+        ]))
+        .unwrap();
+        assert_eq!(toboggan_args.slope, Slope { right: 1, down: 2 });
+        assert!(toboggan_args.tiles);
+        assert_eq!(toboggan_args.style, Style::Svg);
+
+        assert!(super::parse(&args(&["--down", "0"])).is_err());
+        assert!(super::parse(&args(&["--style", "png"])).is_err());
+        assert!(super::parse(&args(&["--legend", "#=tree:-1"])).is_err());
+        assert!(super::parse(&args(&["--format", "json"])).is_err());
+        assert!(super::parse(&args(&["--part", "2"])).is_err());
+    }
+}
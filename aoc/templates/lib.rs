@@ -0,0 +1,37 @@
+use common::{input, Error, Solution};
+
+fn read_lines(content: &str) -> Result<Vec<String>, Error> {
+    Ok(input::lines(content)
+        .map(|(_, line)| line.to_string())
+        .collect())
+}
+
+pub struct DAY_STRUCT;
+
+impl Solution for DAY_STRUCT {
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_lines(content)
+    }
+
+    fn part_one(_lines: &Self::Input) -> Result<Self::PartOne, Error> {
+        // part one
+        Ok(0)
+    }
+
+    fn part_two(_lines: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // part two
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_read_lines() {
+        assert_eq!(super::read_lines("a\nb\n").unwrap(), vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+use std::io;
+
+/// Error is returned whenever puzzle input cannot be read or understood
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse {
+        path: Option<String>,
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+    NoAnswer {
+        path: Option<String>,
+        message: String,
+    },
+    Limit {
+        path: Option<String>,
+        message: String,
+    },
+}
+
+impl Error {
+    /// parse builds an Error for the 1-indexed line and column of the input,
+    /// where snippet is the text of the offending line
+    pub fn parse(line: usize, column: usize, snippet: &str, message: impl Into<String>) -> Error {
+        Error::Parse {
+            path: None,
+            line,
+            column,
+            snippet: snippet.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// at builds an Error pointing at token, which must be a slice of line
+    pub fn at(line_number: usize, line: &str, token: &str, message: impl Into<String>) -> Error {
+        Error::parse(line_number, column(line, token), line, message)
+    }
+
+    /// no_answer builds an Error for an input that was understood but that
+    /// has no answer to the puzzle
+    pub fn no_answer(message: impl Into<String>) -> Error {
+        Error::NoAnswer {
+            path: None,
+            message: message.into(),
+        }
+    }
+
+    /// limit builds an Error for an input that may well have an answer, but
+    /// one that would take more than a set limit to work out
+    pub fn limit(message: impl Into<String>) -> Error {
+        Error::Limit {
+            path: None,
+            message: message.into(),
+        }
+    }
+
+    /// with_path records the file the error was found in
+    pub fn with_path(self, filename: &str) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                snippet,
+                message,
+                ..
+            } => Error::Parse {
+                path: Some(filename.to_string()),
+                line,
+                column,
+                snippet,
+                message,
+            },
+            Error::NoAnswer { message, .. } => Error::NoAnswer {
+                path: Some(filename.to_string()),
+                message,
+            },
+            Error::Limit { message, .. } => Error::Limit {
+                path: Some(filename.to_string()),
+                message,
+            },
+            error => error,
+        }
+    }
+
+    /// diagnostic renders the error over several lines, quoting the
+    /// offending input and marking the column the error was found at
+    pub fn diagnostic(&self) -> String {
+        match self {
+            Error::Io { .. } | Error::NoAnswer { .. } | Error::Limit { .. } => {
+                format!("error: {}", self)
+            }
+            Error::Parse {
+                path,
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                let location = match path {
+                    Some(path) => format!("{}:{}:{}", path, line, column),
+                    None => format!("<input>:{}:{}", line, column),
+                };
+                let marker = " ".repeat(column.saturating_sub(1)) + "^";
+
+                format!(
+                    "error: {}\n{}--> {}\n{} |\n{} | {}\n{} | {}",
+                    message, gutter, location, gutter, line, snippet, gutter, marker
+                )
+            }
+        }
+    }
+}
+
+/// column finds the 1-indexed column at which token starts within line,
+/// falling back to the first column if token is not part of line
+fn column(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    if token_start < line_start || token_start > line_start + line.len() {
+        return 1;
+    }
+
+    line[..token_start - line_start].chars().count() + 1
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "could not read {}: {}", path, source),
+            Error::Parse {
+                path,
+                line,
+                column,
+                message,
+                ..
+            } => match path {
+                Some(path) => write!(f, "{}:{}:{}: {}", path, line, column, message),
+                None => write!(f, "line {}, column {}: {}", line, column, message),
+            },
+            Error::NoAnswer { path, message } => match path {
+                Some(path) => write!(f, "{}: no answer: {}", path, message),
+                None => write!(f, "no answer: {}", message),
+            },
+            Error::Limit { path, message } => match path {
+                Some(path) => write!(f, "{}: {}", path, message),
+                None => write!(f, "{}", message),
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { .. } | Error::NoAnswer { .. } | Error::Limit { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_at() {
+        let line = "nop +0";
+        let (_, val) = line.split_at(4);
+        let error = Error::at(3, line, val, "invalid value");
+        assert_eq!(error.to_string(), "line 3, column 5: invalid value");
+
+        let error = Error::at(3, line, "elsewhere", "invalid value");
+        assert_eq!(error.to_string(), "line 3, column 1: invalid value");
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let error = Error::no_answer("no 2 entries add up to 2020");
+        assert_eq!(error.to_string(), "no answer: no 2 entries add up to 2020");
+        assert_eq!(
+            error.with_path("day_01/src/input").diagnostic(),
+            "error: day_01/src/input: no answer: no 2 entries add up to 2020"
+        );
+    }
+
+    #[test]
+    fn test_limit() {
+        let error = Error::limit("subset search too large: needs 64 bytes, limit 32");
+        assert_eq!(
+            error.with_path("day_01/src/input").diagnostic(),
+            "error: day_01/src/input: subset search too large: needs 64 bytes, limit 32"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let line = "light red bags contain x bright white bag.";
+        let error = Error::at(12, line, &line[23..24], "invalid bag count \"x\"")
+            .with_path("day_07/src/input");
+        assert_eq!(
+            error.diagnostic(),
+            [
+                "error: invalid bag count \"x\"",
+                "  --> day_07/src/input:12:24",
+                "   |",
+                "12 | light red bags contain x bright white bag.",
+                "   |                        ^",
+            ]
+            .join("\n")
+        );
+    }
+}
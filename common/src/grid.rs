@@ -0,0 +1,356 @@
+use crate::input;
+use crate::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Direction is a step of (rows, columns) across a grid, down and right
+/// being positive
+pub type Direction = (isize, isize);
+
+/// ORTHOGONAL lists the four directions sharing an edge: up, left, right
+/// and down
+pub const ORTHOGONAL: [Direction; 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// ADJACENT lists the eight directions sharing an edge or a corner, row by
+/// row from the top left
+pub const ADJACENT: [Direction; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Grid is a rectangular map stored row by row in a single vector, so that
+/// any cell is found in constant time. Cells are addressed by (row, column),
+/// both 0-indexed from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// from_rows builds a grid out of rows that must all be as wide as the
+    /// first, or None when they are not
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// from_fn builds a grid of the given size, asking `cell` for the value
+    /// at every (row, column)
+    pub fn from_fn(height: usize, width: usize, cell: impl Fn(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(height * width);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(cell(row, col));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// parse reads a character map the way input::grid does, converting
+    /// every cell with `cell`
+    pub fn parse<F>(content: &str, cell: F) -> Result<Grid<T>, Error>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        // input::grid already rejects rows of different widths
+        let rows = input::grid(content, cell)?;
+
+        Ok(Grid {
+            width: rows.first().map_or(0, |row| row.len()),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// step moves from a cell in a direction, or None when that leaves the
+    /// grid
+    pub fn step(&self, row: usize, col: usize, direction: Direction) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(direction.0)?;
+        let col = col.checked_add_signed(direction.1)?;
+        if row < self.height && col < self.width {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    /// wrap brings any position back onto the grid, as if it were repeated
+    /// endlessly in every direction. An empty grid has no positions at all.
+    pub fn wrap(&self, row: isize, col: isize) -> Option<(usize, usize)> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width as isize) as usize;
+
+        Some((row, col))
+    }
+
+    /// get_wrapping is get on a grid repeated endlessly in every direction
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        let (row, col) = self.wrap(row, col)?;
+        self.get(row, col)
+    }
+
+    /// neighbours iterates over the cells next to a cell in the given
+    /// directions that are on the grid, along with their positions
+    pub fn neighbours<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(row, col, direction))
+            .map(move |(row, col)| ((row, col), &self[(row, col)]))
+    }
+
+    /// neighbours4 is neighbours in the ORTHOGONAL directions
+    pub fn neighbours4(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(row, col, &ORTHOGONAL)
+    }
+
+    /// neighbours8 is neighbours in the ADJACENT directions
+    pub fn neighbours8(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(row, col, &ADJACENT)
+    }
+
+    /// ray iterates over the cells seen from a cell when looking in a
+    /// direction, the cell itself excluded, until the edge of the grid
+    pub fn ray(&self, row: usize, col: usize, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            position: (row, col),
+            direction,
+        }
+    }
+
+    /// positions iterates over every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// iter iterates over every cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// rows iterates over the rows, from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and a grid without columns has no cells
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// map converts every cell, keeping the shape of the grid
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// render draws the grid with a character per cell, one line per row
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let lines: Vec<String> = self
+            .rows()
+            .map(|row| row.iter().map(&cell).collect())
+            .collect();
+        lines.join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// index panics outside of the grid, like indexing a slice does
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get(row, col) {
+            Some(cell) => cell,
+            None => panic!(
+                "position ({}, {}) is outside of a {}x{} grid",
+                row, col, self.height, self.width
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        match self.get_mut(row, col) {
+            Some(cell) => cell,
+            None => panic!(
+                "position ({}, {}) is outside of a {}x{} grid",
+                row, col, height, width
+            ),
+        }
+    }
+}
+
+/// Display writes every cell, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Ray is the iterator returned by Grid::ray
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    position: (usize, usize),
+    direction: Direction,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.position;
+        self.position = self.grid.step(row, col, self.direction)?;
+        // a direction of (0, 0) would look at the same cell forever
+        if self.position == (row, col) {
+            return None;
+        }
+
+        Some((self.position, &self.grid[self.position]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\r\nghi\njkl", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.height(), grid.width()), (4, 3));
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid[(3, 0)], 'j');
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+
+        let error = Grid::parse("ab\nc\n", Some).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 2 columns, found 1"
+        );
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+
+        let grid = Grid::from_fn(2, 3, |row, col| row * 10 + col);
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec![0, 1, 2], vec![10, 11, 12]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let cells = |neighbours: Vec<((usize, usize), &char)>| -> String {
+            neighbours.into_iter().map(|(_, &c)| c).collect()
+        };
+        assert_eq!(cells(grid.neighbours4(1, 1).collect()), "bdfh");
+        assert_eq!(cells(grid.neighbours8(1, 1).collect()), "abcdfghi");
+        assert_eq!(cells(grid.neighbours8(0, 0).collect()), "bde");
+        assert_eq!(cells(grid.neighbours4(3, 2).collect()), "ik");
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = grid();
+        let cells: String = grid.ray(0, 0, (1, 1)).map(|(_, &c)| c).collect();
+        assert_eq!(cells, "ei");
+        let positions: Vec<(usize, usize)> = grid.ray(3, 1, (-1, 0)).map(|(p, _)| p).collect();
+        assert_eq!(positions, vec![(2, 1), (1, 1), (0, 1)]);
+        assert_eq!(grid.ray(0, 0, (-1, 0)).count(), 0);
+        assert_eq!(grid.ray(0, 0, (0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let grid = grid();
+        assert_eq!(grid.wrap(5, 7), Some((1, 1)));
+        assert_eq!(grid.get_wrapping(-1, -1), Some(&'l'));
+        assert_eq!(grid.get_wrapping(0, 300), Some(&'a'));
+        assert_eq!(Grid::<char>::from_rows(vec![]).unwrap().wrap(0, 0), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = grid();
+        grid[(0, 0)] = 'x';
+        assert_eq!(grid.to_string(), "xbc\ndef\nghi\njkl");
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.render(|&c| c), "XBC\nDEF\nGHI\nJKL");
+        assert_eq!(Grid::<char>::from_rows(vec![]).unwrap().to_string(), "");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::str::FromStr;
+
+/// STDIN is the filename standing for the standard input
+pub const STDIN: &str = "-";
+
+/// display_name is how a filename is referred to in messages
+pub fn display_name(filename: &str) -> &str {
+    if filename == STDIN {
+        "<stdin>"
+    } else {
+        filename
+    }
+}
+
+/// read_to_string loads the whole input file into memory, reading the
+/// standard input instead if the filename is STDIN
+pub fn read_to_string(filename: &str) -> Result<String, Error> {
+    let result = if filename == STDIN {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content).map(|_| content)
+    } else {
+        fs::read_to_string(filename)
+    };
+
+    result.map_err(|source| Error::Io {
+        path: display_name(filename).to_string(),
+        source,
+    })
+}
+
+/// lines iterates over the lines of the input, paired with their 1-indexed
+/// line number. Both "\n" and "\r\n" endings are accepted and a missing
+/// trailing newline is not an issue.
+pub fn lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+/// groups splits the input into records separated by blank lines, each
+/// record being the numbered lines it is made of
+pub fn groups(content: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for (line_number, line) in lines(content) {
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = vec![];
+            }
+        } else {
+            group.push((line_number, line));
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    groups
+}
+
+/// grid reads a rectangular character map, converting every cell with `cell`.
+/// A character that `cell` does not recognise or a row of a different width
+/// than the first is an error.
+pub fn grid<T, F>(content: &str, cell: F) -> Result<Vec<Vec<T>>, Error>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let mut grid: Vec<Vec<T>> = vec![];
+    for (line_number, line) in lines(content) {
+        let mut row = vec![];
+        for (index, c) in line.chars().enumerate() {
+            match cell(c) {
+                Some(value) => row.push(value),
+                None => {
+                    let message = format!("unknown character {:?}", c);
+                    return Err(Error::parse(line_number, index + 1, line, message));
+                }
+            }
+        }
+
+        if let Some(first) = grid.first() {
+            let width = first.len();
+            if row.len() != width {
+                let message = format!("expected {} columns, found {}", width, row.len());
+                return Err(Error::parse(line_number, 1, line, message));
+            }
+        }
+        grid.push(row);
+    }
+
+    Ok(grid)
+}
+
+/// numbers parses one number per line, ignoring blank lines
+pub fn numbers<T>(content: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut numbers = vec![];
+    for (line_number, line) in lines(content) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match line.trim().parse() {
+            Ok(number) => numbers.push(number),
+            Err(why) => {
+                let message = format!("invalid number {:?}: {}", line.trim(), why);
+                return Err(Error::at(line_number, line, line.trim(), message));
+            }
+        }
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_lines() {
+        let lines: Vec<(usize, &str)> = super::lines("a\r\nb\nc").collect();
+        assert_eq!(lines, vec![(1, "a"), (2, "b"), (3, "c")]);
+    }
+
+    #[test]
+    fn test_groups() {
+        let groups = super::groups("abc\n\na\nb\r\n\r\nac\n");
+        assert_eq!(
+            groups,
+            vec![vec![(1, "abc")], vec![(3, "a"), (4, "b")], vec![(6, "ac")]]
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let grid = super::grid("..#\r\n#..", cell).unwrap();
+        assert_eq!(
+            grid,
+            vec![vec![false, false, true], vec![true, false, false]]
+        );
+
+        let error = super::grid("..#\n#x.\n", cell).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unknown character 'x'");
+
+        let error = super::grid("..#\n#.\n", cell).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 3 columns, found 2"
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        let numbers: Vec<u32> = super::numbers("1721\r\n979\n366").unwrap();
+        assert_eq!(numbers, vec![1721, 979, 366]);
+
+        let error = super::numbers::<u32>("1721\n  -979\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid number \"-979\": invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod error;
+pub mod grid;
+pub mod input;
+pub mod log;
+mod solution;
+
+pub use error::Error;
+pub use grid::Grid;
+pub use solution::{solve, time, Answer, Part, Solution, Timings};
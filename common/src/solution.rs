@@ -0,0 +1,157 @@
+use crate::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Part is one of the two halves of a day's puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Solution is implemented once per day: the puzzle input is parsed once into
+/// a day-specific type which both parts then answer from. A part fails with
+/// Error::NoAnswer when the input is well formed but has no answer, and with
+/// Error::Limit when finding the answer would go over a set limit.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(content: &str) -> Result<Self::Input, Error>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Error>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Error>;
+}
+
+/// Answer is the rendered result of a single part, along with how long the
+/// part took to solve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/// solve parses the input of a day and answers the requested part, or both
+/// parts if none is given
+pub fn solve<S: Solution>(content: &str, part: Option<Part>) -> Result<Vec<Answer>, Error> {
+    let input = S::parse(content)?;
+
+    let mut answers = vec![];
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let value = S::part_one(&input)?.to_string();
+        answers.push(Answer {
+            part: Part::One,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        let value = S::part_two(&input)?.to_string();
+        answers.push(Answer {
+            part: Part::Two,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// Timings is how long parsing and each part of a day took
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// time runs a day once over the input, timing parsing and each part
+/// separately. Answers are thrown away, so only the time is measured.
+pub fn time<S: Solution>(content: &str) -> Result<Timings, Error> {
+    let start = Instant::now();
+    let input = black_box(S::parse(content)?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&input)?);
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_two(&input)?);
+    let part_two = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Part, Solution};
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input = usize;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(content: &str) -> Result<Self::Input, Error> {
+            Ok(content.len())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne, Error> {
+            Ok(*input)
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Error> {
+            if *input == 0 {
+                return Err(Error::no_answer("empty input"));
+            }
+            Ok(format!("{} chars", input))
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = super::solve::<Length>("input", None).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].part, Part::One);
+        assert_eq!(answers[0].value, "5");
+        assert_eq!(answers[1].value, "5 chars");
+
+        let answers = super::solve::<Length>("input", Some(Part::Two)).unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+
+        assert!(super::solve::<Length>("", Some(Part::One)).is_ok());
+        assert!(super::solve::<Length>("", None).is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        assert!(super::time::<Length>("input").is_ok());
+    }
+}
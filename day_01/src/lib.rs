@@ -0,0 +1,541 @@
+mod product;
+
+use common::{debug, input, warn, Error, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+pub use product::{BigUint, Magnitude, Product};
+
+/// Expense is one entry of the expense report: a signed amount, optionally
+/// preceded by a label such as "rent"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expense {
+    pub label: Option<String>,
+    pub amount: i64,
+}
+
+impl fmt::Display for Expense {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "{} {}", label, self.amount),
+            None => write!(f, "{}", self.amount),
+        }
+    }
+}
+
+/// read_expenses reads one `[label] amount` entry per line. Everything
+/// after a `#` is a comment, and lines left blank are skipped.
+fn read_expenses(content: &str) -> Result<Vec<Expense>, Error> {
+    let mut expenses = vec![];
+    for (line_number, line) in input::lines(content) {
+        let entry = match line.split_once('#') {
+            Some((entry, _comment)) => entry.trim(),
+            None => line.trim(),
+        };
+        if entry.is_empty() {
+            continue;
+        }
+
+        let (label, amount) = match entry.rsplit_once(char::is_whitespace) {
+            Some((label, amount)) => (Some(label.trim_end().to_string()), amount),
+            None => (None, entry),
+        };
+        let amount = match amount.parse() {
+            Ok(amount) => amount,
+            Err(why) => {
+                let message = format!("invalid amount {:?}: {}", amount, why);
+                return Err(Error::at(line_number, line, amount, message));
+            }
+        };
+        expenses.push(Expense { label, amount });
+    }
+
+    Ok(expenses)
+}
+
+/// amounts leaves the labels out, which the solvers do not need
+pub fn amounts(expenses: &[Expense]) -> Vec<i64> {
+    expenses.iter().map(|expense| expense.amount).collect()
+}
+
+/// Combination is a group of entries adding up to the target, along with
+/// their 0-indexed positions in the expense report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub entries: Vec<i64>,
+}
+
+impl Combination {
+    fn new(numbers: &[i64], mut indices: Vec<usize>) -> Combination {
+        indices.sort_unstable();
+        let entries = indices.iter().map(|&i| numbers[i]).collect();
+        Combination { indices, entries }
+    }
+
+    /// product multiplies the entries together without overflowing
+    pub fn product(&self) -> Product {
+        Product::of(self.entries.iter().copied())
+    }
+
+    /// describe lists the expenses of the combination, labels included
+    pub fn describe(&self, expenses: &[Expense]) -> String {
+        let terms: Vec<String> = self
+            .indices
+            .iter()
+            .map(|&i| expenses[i].to_string())
+            .collect();
+        terms.join(" + ")
+    }
+}
+
+/// find_k_sums returns every combination of k entries, each used at most
+/// once, that add up to target, ordered by their indices. Pairs are found
+/// with a hash of the entries seen so far, and larger groups by fixing all
+/// but two entries over the sorted list and closing in on the last two.
+pub fn find_k_sums(numbers: &[i64], k: usize, target: i64) -> Vec<Combination> {
+    let mut found: Vec<Vec<usize>> = vec![];
+    match k {
+        0 => {}
+        1 => {
+            for (index, &n) in numbers.iter().enumerate() {
+                if n == target {
+                    found.push(vec![index]);
+                }
+            }
+        }
+        2 => find_pairs(numbers, target, &mut found),
+        _ => {
+            let mut sorted: Vec<(i64, usize)> = numbers.iter().copied().zip(0..).collect();
+            sorted.sort_unstable();
+            let mut chosen = vec![];
+            find_sorted(&sorted, 0, k, i128::from(target), &mut chosen, &mut found);
+            for indices in found.iter_mut() {
+                *indices = indices.iter().map(|&i| sorted[i].1).collect();
+            }
+        }
+    }
+
+    let mut combinations: Vec<Combination> = found
+        .into_iter()
+        .map(|indices| Combination::new(numbers, indices))
+        .collect();
+    combinations.sort_unstable_by(|a, b| a.indices.cmp(&b.indices));
+    combinations
+}
+
+/// find_k_sum returns the single combination of k entries adding up to
+/// target. It is an error for there to be none, and a warning for there to
+/// be several, in which case the first one is picked.
+pub fn find_k_sum(numbers: &[i64], k: usize, target: i64) -> Result<Combination, Error> {
+    let mut combinations = find_k_sums(numbers, k, target);
+    if combinations.is_empty() {
+        let message = format!("no {} entries add up to {}", k, target);
+        return Err(Error::no_answer(message));
+    }
+    if combinations.len() > 1 {
+        warn!(
+            "{} combinations of {} entries add up to {}, picking the first of: {:?}",
+            combinations.len(),
+            k,
+            target,
+            combinations
+                .iter()
+                .map(|c| &c.entries)
+                .collect::<Vec<&Vec<i64>>>()
+        );
+    }
+
+    Ok(combinations.swap_remove(0))
+}
+
+/// SUBSET_SUM_BYTES bounds the memory of find_subset_sum, which records a
+/// bit per entry and sum, along with the fewest entries reaching each sum
+/// in four bytes
+pub const SUBSET_SUM_BYTES: u64 = 32 << 20;
+
+/// SubsetSize tells find_subset_sum which subset to look for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsetSize {
+    /// any subset, whatever its size
+    Any,
+    /// a subset with as few entries as possible
+    Minimal,
+}
+
+/// find_subset_sum looks for a subset of the entries, of any size, adding
+/// up to target. The sums reachable with the first entries are worked out
+/// one entry at a time, recording which entries were taken to reach each
+/// sum so that the subset can be walked back from the target. Sums range
+/// from the total of the negative entries to that of the positive ones, so
+/// negative entries widen the table. A target of 0 is met by taking no
+/// entries at all, which is the subset returned for it.
+pub fn find_subset_sum(
+    numbers: &[i64],
+    target: i64,
+    size: SubsetSize,
+) -> Result<Combination, Error> {
+    let no_subset = || {
+        let message = format!("no subset of the entries adds up to {}", target);
+        Error::no_answer(message)
+    };
+
+    let mut lowest: i128 = numbers
+        .iter()
+        .filter(|&&n| n < 0)
+        .map(|&n| i128::from(n))
+        .sum();
+    let mut highest: i128 = numbers
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| i128::from(n))
+        .sum();
+    let target = i128::from(target);
+    // when every entry pulls the same way, sums past the target can never
+    // come back to it
+    if lowest == 0 {
+        highest = highest.min(target);
+    }
+    if highest == 0 {
+        lowest = lowest.max(target);
+    }
+    if target < lowest || target > highest {
+        return Err(no_subset());
+    }
+
+    let width = (highest - lowest + 1) as u128;
+    let bytes = numbers.len() as u128 * width.div_ceil(64) * 8 + width * 4;
+    if bytes > u128::from(SUBSET_SUM_BYTES) {
+        let message = format!(
+            "subset search too large: needs {} bytes, limit {}",
+            bytes, SUBSET_SUM_BYTES
+        );
+        return Err(Error::limit(message));
+    }
+    let width = width as usize;
+    // sums are stored from the lowest one up
+    let position = |sum: i128| (sum - lowest) as usize;
+
+    // fewest entries reaching each sum so far, u32::MAX if it is unreachable
+    let mut fewest: Vec<u32> = vec![u32::MAX; width];
+    fewest[position(0)] = 0;
+    // one bit per sum and entry, set if the entry was taken to reach the sum
+    let mut taken: Vec<Vec<u64>> = Vec::with_capacity(numbers.len());
+    for &n in numbers {
+        let mut row = vec![0u64; width.div_ceil(64)];
+        let step = n.unsigned_abs() as usize;
+        if step < width {
+            // walk away from the sums the entry is added to, so that it is
+            // only taken once per sum
+            let sums: Box<dyn Iterator<Item = (usize, usize)>> = if n >= 0 {
+                Box::new((step..width).rev().map(|sum| (sum, sum - step)))
+            } else {
+                Box::new((0..width - step).map(|sum| (sum, sum + step)))
+            };
+            for (sum, before) in sums {
+                let before = fewest[before];
+                if before == u32::MAX {
+                    continue;
+                }
+                let better = match size {
+                    SubsetSize::Any => fewest[sum] == u32::MAX,
+                    SubsetSize::Minimal => before + 1 < fewest[sum],
+                };
+                if better {
+                    fewest[sum] = before + 1;
+                    row[sum / 64] |= 1 << (sum % 64);
+                }
+            }
+        }
+        taken.push(row);
+    }
+
+    if fewest[position(target)] == u32::MAX {
+        return Err(no_subset());
+    }
+
+    let mut indices = vec![];
+    let mut sum = target;
+    for (index, row) in taken.iter().enumerate().rev() {
+        let at = position(sum);
+        if row[at / 64] & (1 << (at % 64)) != 0 {
+            indices.push(index);
+            sum -= i128::from(numbers[index]);
+        }
+    }
+
+    Ok(Combination::new(numbers, indices))
+}
+
+/// find_pairs pushes the indices of every two entries adding up to target
+fn find_pairs(numbers: &[i64], target: i64, found: &mut Vec<Vec<usize>>) {
+    let mut seen: HashMap<i64, Vec<usize>> = HashMap::new();
+    for (index, &n) in numbers.iter().enumerate() {
+        if let Some(other) = target.checked_sub(n) {
+            if let Some(others) = seen.get(&other) {
+                for &other in others {
+                    found.push(vec![other, index]);
+                }
+            }
+        }
+        seen.entry(n).or_default().push(index);
+    }
+}
+
+/// find_sorted picks k entries of sorted from start onwards adding up to
+/// target, in every possible way. Chosen holds the positions in sorted that
+/// were picked so far and every complete pick is pushed onto found.
+fn find_sorted(
+    sorted: &[(i64, usize)],
+    start: usize,
+    k: usize,
+    target: i128,
+    chosen: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    if k == 2 {
+        if sorted.len() < start + 2 {
+            return;
+        }
+        let (mut low, mut high) = (start, sorted.len() - 1);
+        let mut pick = |a: usize, b: usize| {
+            let mut indices = chosen.clone();
+            indices.push(a);
+            indices.push(b);
+            found.push(indices);
+        };
+        while low < high {
+            let sum = i128::from(sorted[low].0) + i128::from(sorted[high].0);
+            if sum < target {
+                low += 1;
+            } else if sum > target {
+                high -= 1;
+            } else if sorted[low].0 == sorted[high].0 {
+                // every entry in between is equal too, so any two of them
+                // make a pair
+                for a in low..high {
+                    for b in a + 1..=high {
+                        pick(a, b);
+                    }
+                }
+                break;
+            } else {
+                // pair every copy of the low value with every copy of the
+                // high value, then move past both
+                let low_end = (low..high)
+                    .find(|&i| sorted[i].0 != sorted[low].0)
+                    .unwrap_or(high);
+                let high_start = (low_end..=high)
+                    .find(|&i| sorted[i].0 == sorted[high].0)
+                    .unwrap_or(high);
+                for a in low..low_end {
+                    for b in high_start..=high {
+                        pick(a, b);
+                    }
+                }
+                low = low_end;
+                high = high_start - 1;
+            }
+        }
+        return;
+    }
+
+    let largest = match sorted.last() {
+        Some(&(largest, _)) => i128::from(largest),
+        None => return,
+    };
+    for first in start..sorted.len() {
+        let value = i128::from(sorted[first].0);
+        if value * k as i128 > target {
+            // entries are sorted, so even the smallest picks from here on
+            // add up to too much
+            break;
+        }
+        if value + largest * (k as i128 - 1) < target {
+            // even the largest picks cannot reach the target
+            continue;
+        }
+        chosen.push(first);
+        find_sorted(sorted, first + 1, k - 1, target - value, chosen, found);
+        chosen.pop();
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Expense>;
+    type PartOne = Product;
+    type PartTwo = Product;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_expenses(content)
+    }
+
+    fn part_one(expenses: &Self::Input) -> Result<Self::PartOne, Error> {
+        let all_numbers = amounts(expenses);
+        debug!("all_numbers: {:?}", all_numbers);
+
+        // find those two with sum of 2020
+        let combination = find_k_sum(&all_numbers, 2, 2020)?;
+        debug!("combination: {}", combination.describe(expenses));
+
+        // multiply sum entries - result of part 1
+        Ok(combination.product())
+    }
+
+    fn part_two(expenses: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let all_numbers = amounts(expenses);
+
+        // find those three with sum of 2020
+        let combination = find_k_sum(&all_numbers, 3, 2020)?;
+        debug!("combination: {}", combination.describe(expenses));
+
+        // multiply sum entries - result of part 2
+        Ok(combination.product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expense;
+
+    const EXAMPLE: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    fn entries(combinations: Vec<super::Combination>) -> Vec<Vec<i64>> {
+        combinations.into_iter().map(|c| c.entries).collect()
+    }
+
+    #[test]
+    fn test_read_expenses() {
+        let content = "# june\nrent 1200\n\n  -20 # refund\nweekly food +85\n4";
+        let expenses = super::read_expenses(content).unwrap();
+        assert_eq!(
+            expenses,
+            vec![
+                Expense {
+                    label: Some(String::from("rent")),
+                    amount: 1200
+                },
+                Expense {
+                    label: None,
+                    amount: -20
+                },
+                Expense {
+                    label: Some(String::from("weekly food")),
+                    amount: 85
+                },
+                Expense {
+                    label: None,
+                    amount: 4
+                },
+            ]
+        );
+        assert_eq!(expenses[2].to_string(), "weekly food 85");
+
+        let error = super::read_expenses("rent 1200\nfood twelve\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: invalid amount \"twelve\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_find_k_sums() {
+        let pairs = super::find_k_sums(&EXAMPLE, 2, 2020);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].indices, vec![0, 3]);
+        assert_eq!(pairs[0].entries, vec![1721, 299]);
+        assert_eq!(
+            entries(super::find_k_sums(&EXAMPLE, 3, 2020)),
+            vec![vec![979, 366, 675]]
+        );
+        assert_eq!(
+            entries(super::find_k_sums(&EXAMPLE, 4, 3365)),
+            vec![vec![1721, 979, 366, 299]]
+        );
+        assert_eq!(
+            entries(super::find_k_sums(&EXAMPLE, 1, 675)),
+            vec![vec![675]]
+        );
+        assert!(super::find_k_sums(&EXAMPLE, 2, 1).is_empty());
+
+        // an entry cannot be paired with itself, but its copies can
+        assert!(super::find_k_sums(&[1010, 5], 2, 2020).is_empty());
+        let copies = super::find_k_sums(&[1010, 5, 1010, 1010], 2, 2020);
+        let indices: Vec<Vec<usize>> = copies.into_iter().map(|c| c.indices).collect();
+        assert_eq!(indices, vec![vec![0, 2], vec![0, 3], vec![2, 3]]);
+
+        // negative entries are fine, and so are negative targets
+        let numbers = [-5, 10, 3, 2];
+        assert_eq!(
+            entries(super::find_k_sums(&numbers, 2, 5)),
+            vec![vec![-5, 10], vec![3, 2]]
+        );
+        assert_eq!(
+            entries(super::find_k_sums(&numbers, 3, 0)),
+            vec![vec![-5, 3, 2]]
+        );
+        assert!(super::find_k_sums(&[i64::MAX, i64::MIN], 2, i64::MAX).is_empty());
+
+        // every distinct group is found, duplicates included
+        let numbers = [1, 2, 3, 4, 5, 3];
+        assert_eq!(
+            entries(super::find_k_sums(&numbers, 3, 9)),
+            vec![vec![1, 3, 5], vec![1, 5, 3], vec![2, 3, 4], vec![2, 4, 3],]
+        );
+    }
+
+    #[test]
+    fn test_find_subset_sum() {
+        use super::SubsetSize::{Any, Minimal};
+
+        let subset = super::find_subset_sum(&EXAMPLE, 2020, Any).unwrap();
+        assert_eq!(subset.entries.iter().sum::<i64>(), 2020);
+        let subset = super::find_subset_sum(&EXAMPLE, 2020, Minimal).unwrap();
+        assert_eq!(subset.entries, vec![1721, 299]);
+
+        // three small entries reach the target before the larger pair does
+        let numbers = [1, 2, 3, 6, 0];
+        let subset = super::find_subset_sum(&numbers, 6, Any).unwrap();
+        assert_eq!(subset.indices, vec![0, 1, 2]);
+        let subset = super::find_subset_sum(&numbers, 6, Minimal).unwrap();
+        assert_eq!(subset.indices, vec![3]);
+
+        assert!(super::find_subset_sum(&EXAMPLE, 2, Any).is_err());
+        assert!(super::find_subset_sum(&EXAMPLE, 9999, Any).is_err());
+
+        // negative entries can bring the sum back down
+        let numbers = [5, -3, 10, -7];
+        let subset = super::find_subset_sum(&numbers, 3, Minimal).unwrap();
+        assert_eq!(subset.entries, vec![10, -7]);
+        let subset = super::find_subset_sum(&numbers, -10, Any).unwrap();
+        assert_eq!(subset.entries, vec![-3, -7]);
+
+        // solvable, but only with a table far too large
+        let numbers = [-1, 4_000_000_000];
+        let subset = super::find_subset_sum(&numbers, 3_999_999_999, Any);
+        assert!(matches!(subset, Err(common::Error::Limit { .. })));
+        // a single entry, whose table of fewest entries alone is too large
+        let subset = super::find_subset_sum(&[100_000_000], 100_000_000, Any);
+        assert_eq!(
+            subset.unwrap_err().to_string(),
+            "subset search too large: needs 412500012 bytes, limit 33554432"
+        );
+
+        // taking nothing adds up to 0
+        let subset = super::find_subset_sum(&EXAMPLE, 0, Minimal).unwrap();
+        assert!(subset.indices.is_empty());
+    }
+
+    #[test]
+    fn test_find_k_sum() {
+        let combination = super::find_k_sum(&EXAMPLE, 3, 2020).unwrap();
+        assert_eq!(combination.indices, vec![1, 2, 4]);
+        assert!(super::find_k_sum(&EXAMPLE, 2, 1).is_err());
+
+        // ambiguous inputs pick the first combination
+        let combination = super::find_k_sum(&[1, 2, 3, 4], 2, 5).unwrap();
+        assert_eq!(combination.entries, vec![1, 4]);
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+/// Product is the product of a combination's entries: its sign, and its
+/// magnitude held in the narrowest type it fits in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Product {
+    pub negative: bool,
+    pub magnitude: Magnitude,
+}
+
+impl Product {
+    /// of multiplies every entry together, the product of none being 1
+    pub fn of(entries: impl IntoIterator<Item = i64>) -> Product {
+        let mut negative = false;
+        let mut magnitude = Magnitude::U64(1);
+        for n in entries {
+            negative ^= n < 0;
+            magnitude = magnitude.times(n.unsigned_abs());
+        }
+
+        Product {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+/// Magnitude is an unsigned product that never overflows: it moves on to
+/// u128 once u64 is not enough, and to an arbitrary precision integer after
+/// that
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Magnitude {
+    U64(u64),
+    U128(u128),
+    Big(BigUint),
+}
+
+impl Magnitude {
+    fn times(self, n: u64) -> Magnitude {
+        match self {
+            Magnitude::U64(p) => match p.checked_mul(n) {
+                Some(p) => Magnitude::U64(p),
+                None => Magnitude::U128(u128::from(p)).times(n),
+            },
+            Magnitude::U128(p) => match p.checked_mul(u128::from(n)) {
+                Some(p) => Magnitude::U128(p),
+                None => Magnitude::Big(BigUint::from(p).times(n)),
+            },
+            Magnitude::Big(p) => Magnitude::Big(p.times(n)),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            Magnitude::U64(p) => *p == 0,
+            Magnitude::U128(p) => *p == 0,
+            Magnitude::Big(p) => p.is_zero(),
+        }
+    }
+}
+
+impl fmt::Display for Magnitude {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Magnitude::U64(p) => write!(f, "{}", p),
+            Magnitude::U128(p) => write!(f, "{}", p),
+            Magnitude::Big(p) => write!(f, "{}", p),
+        }
+    }
+}
+
+/// BigUint is an unsigned integer of any size, stored as base 2^64 limbs
+/// with the least significant first. Only what products need is supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    fn times(mut self, n: u64) -> BigUint {
+        let mut carry: u128 = 0;
+        for limb in self.limbs.iter_mut() {
+            let wide = u128::from(*limb) * u128::from(n) + carry;
+            *limb = wide as u64;
+            carry = wide >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+
+        self
+    }
+
+    /// divide_small divides in place and returns the remainder
+    fn divide_small(&mut self, divisor: u64) -> u64 {
+        let mut remainder: u128 = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let wide = (remainder << 64) | u128::from(*limb);
+            *limb = (wide / u128::from(divisor)) as u64;
+            remainder = wide % u128::from(divisor);
+        }
+        while self.limbs.len() > 1 && self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+
+        remainder as u64
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&limb| limb == 0)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> BigUint {
+        BigUint {
+            limbs: vec![n as u64, (n >> 64) as u64],
+        }
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // peel off 19 decimal digits at a time, the most a u64 can hold
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut rest = self.clone();
+        let mut chunks = vec![];
+        loop {
+            chunks.push(rest.divide_small(CHUNK));
+            if rest.is_zero() {
+                break;
+            }
+        }
+
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        for chunk in chunks {
+            write!(f, "{:019}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Magnitude, Product};
+
+    #[test]
+    fn test_of() {
+        assert_eq!(Product::of(vec![]).to_string(), "1");
+        let product = Product::of(vec![1721, 299]);
+        assert_eq!(product.magnitude, Magnitude::U64(514579));
+        assert!(!product.negative);
+
+        let max = i64::from(u32::MAX);
+        let product = Product::of(vec![max, max, max]);
+        assert!(matches!(product.magnitude, Magnitude::U128(_)));
+        assert_eq!(product.to_string(), "79228162458924105385300197375");
+
+        let product = Product::of(vec![max; 10]);
+        assert!(matches!(product.magnitude, Magnitude::Big(_)));
+        assert_eq!(
+            product.to_string(),
+            "2135987030947677723507799297059764828455305035445909852797945603065119075283186535438158212890625"
+        );
+
+        let product = Product::of(vec![1_000_000_007; 7]);
+        assert_eq!(
+            product.to_string(),
+            "1000000049000001029000012005000084035000352947000823543000823543"
+        );
+
+        assert_eq!(Product::of(vec![-20, 85, -3]).to_string(), "5100");
+        assert_eq!(Product::of(vec![-20, 85]).to_string(), "-1700");
+        assert_eq!(
+            Product::of(vec![-max; 3]).to_string(),
+            "-79228162458924105385300197375"
+        );
+        assert_eq!(
+            Product::of(vec![i64::MIN]).to_string(),
+            "-9223372036854775808"
+        );
+        assert_eq!(Product::of(vec![-5, 0]).to_string(), "0");
+    }
+}
@@ -0,0 +1,89 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use unicode_segmentation::Graphemes;
+
+/// Unit is what policies count and index passwords by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// Unicode scalar values, as `str::chars` yields them
+    Chars,
+    /// grapheme clusters, the characters a reader sees, so that `é` written
+    /// as `e` and a combining accent, or a family emoji, is one of them
+    Graphemes,
+}
+
+impl Unit {
+    /// split cuts text into its units, in order
+    pub fn split(self, text: &str) -> Vec<&str> {
+        match self {
+            Unit::Chars => text
+                .char_indices()
+                .map(|(index, c)| &text[index..index + c.len_utf8()])
+                .collect(),
+            Unit::Graphemes => graphemes(text).collect(),
+        }
+    }
+
+    /// plural names the unit in messages
+    pub fn plural(self) -> &'static str {
+        match self {
+            Unit::Chars => "characters",
+            Unit::Graphemes => "graphemes",
+        }
+    }
+}
+
+/// graphemes iterates over the extended grapheme clusters of text, as the
+/// boundary rules of Unicode Standard Annex #29 define them
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    text.graphemes(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+
+    #[test]
+    fn test_graphemes() {
+        let cases: &[(&str, &[&str])] = &[
+            ("abc", &["a", "b", "c"]),
+            ("e\u{301}te\u{301}", &["e\u{301}", "t", "e\u{301}"]),
+            ("\r\n\n", &["\r\n", "\n"]),
+            ("\u{1F44D}\u{1F3FD}!", &["\u{1F44D}\u{1F3FD}", "!"]),
+            (
+                "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+                &["\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"],
+            ),
+            ("a\u{200D}\u{1F469}", &["a\u{200D}", "\u{1F469}"]),
+            (
+                "\u{1F1EC}\u{1F1F7}\u{1F1EB}",
+                &["\u{1F1EC}\u{1F1F7}", "\u{1F1EB}"],
+            ),
+            ("\u{2764}\u{FE0F}", &["\u{2764}\u{FE0F}"]),
+            (
+                "\u{1100}\u{1161}\u{11A8}\u{AC00}",
+                &["\u{1100}\u{1161}\u{11A8}", "\u{AC00}"],
+            ),
+            // spacing marks and prepended letters join their base
+            ("\u{0BA8}\u{0BBF}", &["\u{0BA8}\u{0BBF}"]),
+            ("\u{0E01}\u{0E33}", &["\u{0E01}\u{0E33}"]),
+            ("\u{0995}\u{09BF}", &["\u{0995}\u{09BF}"]),
+            ("\u{0600}1", &["\u{0600}1"]),
+            // a leading consonant only joins a vowel or a syllable
+            ("\u{1100}\u{11A8}", &["\u{1100}", "\u{11A8}"]),
+            // controls stand alone, marks after them too
+            ("a\u{2028}\u{301}", &["a", "\u{2028}", "\u{301}"]),
+            ("a\u{200B}b", &["a", "\u{200B}", "b"]),
+            ("", &[]),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(super::graphemes(text).collect::<Vec<&str>>(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(Unit::Chars.split("e\u{301}x"), vec!["e", "\u{301}", "x"]);
+        assert_eq!(Unit::Graphemes.split("e\u{301}x"), vec!["e\u{301}", "x"]);
+    }
+}
@@ -0,0 +1,277 @@
+mod grapheme;
+mod policy;
+
+use common::{debug, input, Error, Solution};
+use std::fmt;
+
+pub use grapheme::{graphemes, Graphemes, Unit};
+pub use policy::{
+    parse_policy, CharClass, CharacterClass, CountRange, DistinctLetters, ExactlyOnePosition,
+    ForbiddenSubstrings, MinLength, PasswordPolicy,
+};
+
+/// Entry is one line of the password list: a policy rule followed by the
+/// password it applies to, as in `1-3 a: abcde`. The token the rule is about
+/// can be longer than a single letter, as in `1-3 ab: xxabab`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    floor: u32,
+    ceil: u32,
+    token: String,
+    password: String,
+}
+
+/// The parts of an entry are read only, so that policies outside this crate
+/// can look at them but every entry still comes from a valid line.
+impl Entry {
+    /// floor is the first number of the rule
+    pub fn floor(&self) -> u32 {
+        self.floor
+    }
+
+    /// ceil is the second number of the rule
+    pub fn ceil(&self) -> u32 {
+        self.ceil
+    }
+
+    /// token is what the rule is about, one letter or more
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// password is the text the rule applies to
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.floor, self.ceil, self.token, self.password
+        )
+    }
+}
+
+/// Cursor walks through a line of the password list, following the grammar
+///
+/// ```text
+/// entry    = number "-" number " " token ":" " " password
+/// number   = digit+
+/// token    = (any character but " " and ":")+
+/// password = (any character)*
+/// ```
+///
+/// and pointing its errors at where the line strays from it.
+#[derive(Clone, Copy)]
+struct Cursor<'a> {
+    line_number: usize,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn error(&self, message: String) -> Error {
+        Error::at(self.line_number, self.line, self.rest, message)
+    }
+
+    /// take consumes the longest prefix whose characters match
+    fn take(&mut self, matches: impl Fn(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !matches(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.rest.strip_prefix(expected) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(format!("expected {:?}", expected))),
+        }
+    }
+
+    fn number(&mut self, what: &str) -> Result<u32, Error> {
+        let start = *self;
+        let digits = self.take(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(start.error(format!("expected the {}, a number", what)));
+        }
+        digits
+            .parse()
+            .map_err(|why| start.error(format!("invalid {} {:?}: {}", what, digits, why)))
+    }
+
+    fn token(&mut self) -> Result<String, Error> {
+        let token = self.take(|c| c != ' ' && c != ':');
+        if token.is_empty() {
+            return Err(self.error(String::from("expected the letters to look for")));
+        }
+        Ok(token.to_string())
+    }
+}
+
+fn read_entry(line_number: usize, line: &str) -> Result<Entry, Error> {
+    let mut cursor = Cursor {
+        line_number,
+        line,
+        rest: line,
+    };
+
+    let floor = cursor.number("floor")?;
+    cursor.expect('-')?;
+    let ceil = cursor.number("ceil")?;
+    cursor.expect(' ')?;
+    let token = cursor.token()?;
+    cursor.expect(':')?;
+    cursor.expect(' ')?;
+    // the password is the rest of the line, spaces included
+    let password = cursor.rest.to_string();
+
+    Ok(Entry {
+        floor,
+        ceil,
+        token,
+        password,
+    })
+}
+
+fn read_entries(content: &str) -> Result<Vec<Entry>, Error> {
+    let mut entries: Vec<Entry> = vec![];
+    for (line_number, line) in input::lines(content) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(read_entry(line_number, line)?);
+    }
+
+    Ok(entries)
+}
+
+/// count_valid tells how many entries satisfy the policy
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy, unit: Unit) -> u32 {
+    entries
+        .iter()
+        .filter(|entry| policy.is_valid(entry, unit))
+        .count() as u32
+}
+
+/// Verdict is how an entry fared under one policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    /// the 0-indexed position of the entry in the list
+    pub entry: usize,
+    pub policy: String,
+    /// why the entry violates the policy, if it does
+    pub violation: Option<String>,
+}
+
+/// audit checks every entry under every policy, returning a verdict for
+/// each, entry by entry
+pub fn audit(entries: &[Entry], policies: &[Box<dyn PasswordPolicy>], unit: Unit) -> Vec<Verdict> {
+    let mut verdicts = vec![];
+    for (index, entry) in entries.iter().enumerate() {
+        for policy in policies {
+            verdicts.push(Verdict {
+                entry: index,
+                policy: policy.to_string(),
+                violation: policy.check(entry, unit).err(),
+            });
+        }
+    }
+
+    verdicts
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Entry>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_entries(content)
+    }
+
+    fn part_one(entries: &Self::Input) -> Result<Self::PartOne, Error> {
+        debug!("entries: {:?}", entries);
+
+        // calculate part 1
+        Ok(count_valid(entries, &CountRange, Unit::Chars))
+    }
+
+    fn part_two(entries: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // calculate part 2
+        Ok(count_valid(
+            entries,
+            &ExactlyOnePosition { strict: false },
+            Unit::Chars,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_read_entries() {
+        let entries =
+            super::read_entries("1-3 a: abcde\r\n1-3 b: cdefg\r\n2-9 c: ccccccccc").unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].floor(), 2);
+        assert_eq!(entries[2].ceil(), 9);
+        assert_eq!(entries[2].token(), "c");
+        assert_eq!(entries[2].password(), "ccccccccc");
+
+        let entries = super::read_entries("1-3 ab: xxab ab\n").unwrap();
+        assert_eq!(entries[0].token(), "ab");
+        assert_eq!(entries[0].password(), "xxab ab");
+
+        let errors = [
+            ("1-3 a: abcde\n1-x b: cdefg\n", "line 2, column 3: expected the ceil, a number"),
+            ("1-3 a abcde\n", "line 1, column 6: expected ':'"),
+            ("1-3 a:abcde\n", "line 1, column 7: expected ' '"),
+            ("1-3 : abcde\n", "line 1, column 5: expected the letters to look for"),
+            ("13 a: abcde\n", "line 1, column 3: expected '-'"),
+            (
+                "1-99999999999 a: abcde\n",
+                "line 1, column 3: invalid ceil \"99999999999\": number too large to fit in target type",
+            ),
+        ];
+        for (content, message) in errors.iter() {
+            let error = super::read_entries(content).unwrap_err();
+            assert_eq!(error.to_string(), *message);
+        }
+    }
+
+    #[test]
+    fn test_audit() {
+        let entries = super::read_entries("1-3 a: abcde\n1-3 b: cdefg\n").unwrap();
+        let policies = [
+            super::parse_policy("count").unwrap(),
+            super::parse_policy("min-length=5").unwrap(),
+        ];
+        let verdicts = super::audit(&entries, &policies, super::Unit::Chars);
+        assert_eq!(verdicts.len(), 4);
+        assert_eq!(verdicts[0].violation, None);
+        assert_eq!(verdicts[2].entry, 1);
+        assert_eq!(verdicts[2].policy, "count");
+        assert_eq!(
+            verdicts[2].violation.as_deref(),
+            Some("letter 'b' appears 0 times, allowed 1-3")
+        );
+        assert_eq!(verdicts[3].violation, None);
+    }
+
+    #[test]
+    fn test_display() {
+        let content = "1-3 a: abcde\n1-3 ab: xx abab\n2-9 c: ccccccccc";
+        let entries = super::read_entries(content).unwrap();
+        let lines: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+        assert_eq!(lines.join("\n"), content);
+    }
+}
@@ -0,0 +1,670 @@
+use crate::{Entry, Unit};
+use std::collections::HashSet;
+use std::fmt;
+
+/// PasswordPolicy decides whether the password of an entry is acceptable,
+/// explaining why when it is not. Policies can follow the rule written in
+/// the entry itself, as the two puzzle policies do, or ignore it. Display
+/// gives back the spec the policy is parsed from by parse_policy.
+///
+/// Lengths, positions and counts are measured in the given unit, and a
+/// token or substring only matches on whole units, so that under
+/// Unit::Graphemes `e` is not found in `é` written with a combining accent.
+pub trait PasswordPolicy: fmt::Display {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String>;
+
+    fn is_valid(&self, entry: &Entry, unit: Unit) -> bool {
+        self.check(entry, unit).is_ok()
+    }
+}
+
+/// quote names the token of an entry in explanations
+fn quote(token: &str, unit: Unit) -> String {
+    if unit.split(token).len() == 1 {
+        format!("letter '{}'", token)
+    } else {
+        format!("letters '{}'", token)
+    }
+}
+
+/// count_matches counts the non-overlapping occurrences of needle in
+/// haystack, both split into units, from left to right
+fn count_matches(haystack: &[&str], needle: &[&str]) -> usize {
+    if needle.is_empty() {
+        return haystack.len() + 1;
+    }
+
+    let mut count = 0;
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        if haystack[start..].starts_with(needle) {
+            count += 1;
+            start += needle.len();
+        } else {
+            start += 1;
+        }
+    }
+
+    count
+}
+
+/// CountRange is the policy of part one: the token of the entry must
+/// appear between floor and ceil times, inclusive, without overlapping
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        // measure how many times entry.token appears in entry.password
+        let password = unit.split(&entry.password);
+        let token = unit.split(&entry.token);
+        let letter_counter = count_matches(&password, &token) as u32;
+        // if letter_counter is between floor and ceil inclusive, it's valid
+        if letter_counter >= entry.floor && letter_counter <= entry.ceil {
+            return Ok(());
+        }
+
+        Err(format!(
+            "{} appears {} times, allowed {}-{}",
+            quote(&entry.token, unit),
+            letter_counter,
+            entry.floor,
+            entry.ceil
+        ))
+    }
+}
+
+impl fmt::Display for CountRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "count")
+    }
+}
+
+/// ExactlyOnePosition is the policy of part two: the token of the entry
+/// must start at exactly one of the 1-indexed positions floor and ceil. A
+/// position that is 0 or past the end of the password does not contain the
+/// token, unless strict, in which case the entry is rejected as malformed.
+pub struct ExactlyOnePosition {
+    pub strict: bool,
+}
+
+/// starts_at tells whether the token starts at the 1-indexed position of
+/// the password, or None when the password has no such position
+fn starts_at(password: &[&str], token: &[&str], position: u32) -> Option<bool> {
+    let index = (position as usize).checked_sub(1)?;
+    if index >= password.len() {
+        return None;
+    }
+    Some(password[index..].starts_with(token))
+}
+
+impl PasswordPolicy for ExactlyOnePosition {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        let password = unit.split(&entry.password);
+        let token = unit.split(&entry.token);
+        let mut found = vec![];
+        for &position in &[entry.floor, entry.ceil] {
+            match starts_at(&password, &token, position) {
+                Some(at) => found.push(at),
+                None if self.strict => {
+                    return Err(format!(
+                        "malformed: no position {} in a password of {} {}",
+                        position,
+                        password.len(),
+                        unit.plural()
+                    ))
+                }
+                None => found.push(false),
+            }
+        }
+        let (at_a, at_b) = (found[0], found[1]);
+
+        // entry.token must appear exactly once in those two positions
+        match (at_a, at_b) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err(format!(
+                "positions {} and {} both contain {}",
+                entry.floor,
+                entry.ceil,
+                quote(&entry.token, unit)
+            )),
+            (false, false) => Err(format!(
+                "neither position {} nor {} contains {}",
+                entry.floor,
+                entry.ceil,
+                quote(&entry.token, unit)
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ExactlyOnePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.strict {
+            write!(f, "positions=strict")
+        } else {
+            write!(f, "positions")
+        }
+    }
+}
+
+/// DistinctLetters requires at least min different characters
+pub struct DistinctLetters {
+    pub min: usize,
+}
+
+impl PasswordPolicy for DistinctLetters {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        let distinct: HashSet<&str> = unit.split(&entry.password).into_iter().collect();
+        if distinct.len() >= self.min {
+            return Ok(());
+        }
+
+        Err(format!(
+            "{} distinct {}, at least {} required",
+            distinct.len(),
+            unit.plural(),
+            self.min
+        ))
+    }
+}
+
+impl fmt::Display for DistinctLetters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "distinct={}", self.min)
+    }
+}
+
+/// ForbiddenSubstrings rejects passwords containing any of the substrings
+pub struct ForbiddenSubstrings {
+    pub substrings: Vec<String>,
+}
+
+impl PasswordPolicy for ForbiddenSubstrings {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        let password = unit.split(&entry.password);
+        let found: Vec<String> = self
+            .substrings
+            .iter()
+            .filter(|substring| count_matches(&password, &unit.split(substring)) > 0)
+            .map(|substring| format!("'{}'", substring))
+            .collect();
+        if found.is_empty() {
+            return Ok(());
+        }
+
+        Err(format!("contains forbidden {}", found.join(", ")))
+    }
+}
+
+impl fmt::Display for ForbiddenSubstrings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "forbid={}", self.substrings.join(","))
+    }
+}
+
+/// MinLength requires passwords of at least min characters
+pub struct MinLength {
+    pub min: usize,
+}
+
+impl PasswordPolicy for MinLength {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        let length = unit.split(&entry.password).len();
+        if length >= self.min {
+            return Ok(());
+        }
+
+        Err(format!(
+            "{} {} long, at least {} required",
+            length,
+            unit.plural(),
+            self.min
+        ))
+    }
+}
+
+impl fmt::Display for MinLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "min-length={}", self.min)
+    }
+}
+
+/// CharClass is a set of characters written like a regular expression
+/// class, such as `[a-z0-9_]` or `[^aeiou]`. A backslash escapes the next
+/// character, so that `]`, `-` and `^` can be part of the set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharClass {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    pub fn contains(&self, c: char) -> bool {
+        let listed = self
+            .ranges
+            .iter()
+            .any(|&(first, last)| first <= c && c <= last);
+        listed != self.negated
+    }
+
+    /// parse reads a class at the start of spec, returning it along with
+    /// the rest of spec
+    fn parse(spec: &str) -> Result<(CharClass, &str), String> {
+        let mut chars = spec.char_indices().peekable();
+        if chars.next().map(|(_, c)| c) != Some('[') {
+            return Err(format!("character class {:?} must start with [", spec));
+        }
+        let negated = chars.next_if(|&(_, c)| c == '^').is_some();
+
+        let mut ranges = vec![];
+        loop {
+            let first = match chars.next() {
+                Some((end, ']')) => {
+                    return Ok((CharClass { negated, ranges }, &spec[end + 1..]));
+                }
+                Some((_, '\\')) => chars.next(),
+                other => other,
+            };
+            let first = match first {
+                Some((_, c)) => c,
+                None => return Err(format!("character class {:?} is not closed", spec)),
+            };
+
+            // a dash right before the closing bracket is a plain dash
+            let mut lookahead = chars.clone();
+            let is_range = matches!(lookahead.next(), Some((_, '-')))
+                && !matches!(lookahead.next(), Some((_, ']')) | None);
+            if !is_range {
+                ranges.push((first, first));
+                continue;
+            }
+
+            chars.next();
+            let last = match chars.next() {
+                Some((_, '\\')) => chars.next().map(|(_, c)| c),
+                other => other.map(|(_, c)| c),
+            };
+            match last {
+                Some(last) if first <= last => ranges.push((first, last)),
+                Some(last) => return Err(format!("invalid range {}-{}", first, last)),
+                None => return Err(format!("character class {:?} is not closed", spec)),
+            }
+        }
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let escape = |c: char| match c {
+            ']' | '-' | '^' | '\\' => format!("\\{}", c),
+            _ => c.to_string(),
+        };
+
+        write!(f, "[")?;
+        if self.negated {
+            write!(f, "^")?;
+        }
+        for &(first, last) in &self.ranges {
+            if first == last {
+                write!(f, "{}", escape(first))?;
+            } else {
+                write!(f, "{}-{}", escape(first), escape(last))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// CharacterClass requires the number of characters of the password that
+/// belong to the class to be between min and max, inclusive. A grapheme
+/// belongs to the class when its first character does, so that `é` is a
+/// lowercase letter however it is written. It is written
+/// with a regular expression like quantifier: `[0-9]{2,}` asks for at least
+/// two digits and `[^a-z]{0}` for nothing but lowercase letters. Without a
+/// quantifier at least one character of the class is required.
+pub struct CharacterClass {
+    pub class: CharClass,
+    pub min: usize,
+    pub max: Option<usize>,
+}
+
+impl CharacterClass {
+    fn parse(spec: &str) -> Result<CharacterClass, String> {
+        let (class, quantifier) = CharClass::parse(spec)?;
+        let invalid = || format!("invalid quantifier {:?}", quantifier);
+        let bound = |value: &str| value.parse::<usize>().map_err(|_| invalid());
+
+        let (min, max) = if quantifier.is_empty() {
+            (1, None)
+        } else {
+            let bounds = quantifier
+                .strip_prefix('{')
+                .and_then(|rest| rest.strip_suffix('}'))
+                .ok_or_else(invalid)?;
+            match bounds.split_once(',') {
+                None => (bound(bounds)?, Some(bound(bounds)?)),
+                Some((min, "")) => (bound(min)?, None),
+                Some((min, max)) => (bound(min)?, Some(bound(max)?)),
+            }
+        };
+        if max.is_some_and(|max| max < min) {
+            return Err(invalid());
+        }
+
+        Ok(CharacterClass { class, min, max })
+    }
+}
+
+impl PasswordPolicy for CharacterClass {
+    fn check(&self, entry: &Entry, unit: Unit) -> Result<(), String> {
+        let count = unit
+            .split(&entry.password)
+            .iter()
+            .filter_map(|text| text.chars().next())
+            .filter(|&c| self.class.contains(c))
+            .count();
+        if count >= self.min && count <= self.max.unwrap_or(usize::MAX) {
+            return Ok(());
+        }
+
+        let allowed = match self.max {
+            Some(max) if max == self.min => format!("{}", max),
+            Some(max) => format!("{}-{}", self.min, max),
+            None => format!("at least {}", self.min),
+        };
+        Err(format!(
+            "{} {} from {}, allowed {}",
+            count,
+            unit.plural(),
+            self.class,
+            allowed
+        ))
+    }
+}
+
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "class={}", self.class)?;
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{{{}}}", max),
+            Some(max) => write!(f, "{{{},{}}}", self.min, max),
+            None if self.min == 1 => Ok(()),
+            None => write!(f, "{{{},}}", self.min),
+        }
+    }
+}
+
+/// parse_policy builds a policy from its spec:
+///
+/// - `count` and `positions` for the policies of part one and two, and
+///   `positions=strict` to reject positions outside the password
+/// - `distinct=N` for at least N different characters
+/// - `forbid=abc,xyz` to reject passwords containing any of the substrings
+/// - `min-length=N` for passwords of at least N characters
+/// - `class=[0-9]{2,}` for a number of characters from a class
+pub fn parse_policy(spec: &str) -> Result<Box<dyn PasswordPolicy>, String> {
+    let (name, value) = match spec.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (spec, None),
+    };
+    let number = |value: Option<&str>| match value.map(str::parse::<usize>) {
+        Some(Ok(n)) => Ok(n),
+        _ => Err(format!("policy {} needs a number, as in {}=3", name, name)),
+    };
+
+    match (name, value) {
+        ("count", None) => Ok(Box::new(CountRange)),
+        ("positions", None) => Ok(Box::new(ExactlyOnePosition { strict: false })),
+        ("positions", Some("strict")) => Ok(Box::new(ExactlyOnePosition { strict: true })),
+        ("distinct", _) => Ok(Box::new(DistinctLetters {
+            min: number(value)?,
+        })),
+        ("min-length", _) => Ok(Box::new(MinLength {
+            min: number(value)?,
+        })),
+        ("forbid", Some(value)) => {
+            let substrings: Vec<String> = value.split(',').map(String::from).collect();
+            // an empty substring is in every password
+            if substrings.iter().any(String::is_empty) {
+                return Err(format!("policy forbid has an empty substring: {}", spec));
+            }
+            Ok(Box::new(ForbiddenSubstrings { substrings }))
+        }
+        ("class", Some(value)) => Ok(Box::new(CharacterClass::parse(value)?)),
+        _ => Err(format!("unknown policy: {}", spec)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Entry, Unit};
+
+    fn entry(password: &str) -> Entry {
+        Entry {
+            floor: 1,
+            ceil: 3,
+            token: String::from("a"),
+            password: password.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_builtin_policies() {
+        let count = super::parse_policy("count").unwrap();
+        assert!(count.is_valid(&entry("abcde"), Unit::Chars));
+        assert!(!count.is_valid(&entry("aaaa"), Unit::Chars));
+
+        let positions = super::parse_policy("positions").unwrap();
+        assert!(positions.is_valid(&entry("abcde"), Unit::Chars));
+        assert!(!positions.is_valid(&entry("abade"), Unit::Chars));
+        assert!(!positions.is_valid(&entry("bbbde"), Unit::Chars));
+
+        let tokens = Entry {
+            floor: 1,
+            ceil: 3,
+            token: String::from("ab"),
+            password: String::from("xxababab"),
+        };
+        assert!(count.is_valid(&tokens, Unit::Chars));
+        assert!(positions.is_valid(&tokens, Unit::Chars));
+        let both = Entry {
+            floor: 3,
+            ceil: 5,
+            ..tokens
+        };
+        assert!(!positions.is_valid(&both, Unit::Chars));
+
+        let distinct = super::parse_policy("distinct=4").unwrap();
+        assert!(distinct.is_valid(&entry("abcd"), Unit::Chars));
+        assert!(!distinct.is_valid(&entry("abcabc"), Unit::Chars));
+
+        let forbid = super::parse_policy("forbid=abc,123").unwrap();
+        assert!(forbid.is_valid(&entry("ab12c3"), Unit::Chars));
+        assert!(!forbid.is_valid(&entry("x123"), Unit::Chars));
+
+        let min_length = super::parse_policy("min-length=5").unwrap();
+        assert!(min_length.is_valid(&entry("abcde"), Unit::Chars));
+        assert!(!min_length.is_valid(&entry("abcd"), Unit::Chars));
+    }
+
+    #[test]
+    fn test_check() {
+        let check = |spec: &str, password: &str| {
+            let policy = super::parse_policy(spec).unwrap();
+            policy.check(&entry(password), Unit::Chars).unwrap_err()
+        };
+
+        assert_eq!(
+            check("count", "aaaaa"),
+            "letter 'a' appears 5 times, allowed 1-3"
+        );
+        assert_eq!(
+            check("positions", "abade"),
+            "positions 1 and 3 both contain letter 'a'"
+        );
+        assert_eq!(
+            check("positions", "bbbde"),
+            "neither position 1 nor 3 contains letter 'a'"
+        );
+        assert_eq!(
+            check("distinct=4", "abab"),
+            "2 distinct characters, at least 4 required"
+        );
+        assert_eq!(
+            check("forbid=ab,cd,xy", "abcd"),
+            "contains forbidden 'ab', 'cd'"
+        );
+        assert_eq!(
+            check("min-length=8", "abcd"),
+            "4 characters long, at least 8 required"
+        );
+        assert_eq!(
+            check("class=[0-9]{1,2}", "a123"),
+            "3 characters from [0-9], allowed 1-2"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_positions() {
+        let at = |floor: u32, ceil: u32, password: &str| Entry {
+            floor,
+            ceil,
+            token: String::from("a"),
+            password: password.to_string(),
+        };
+
+        let lenient = super::parse_policy("positions").unwrap();
+        assert!(lenient.is_valid(&at(0, 1, "abc"), Unit::Chars));
+        assert!(lenient.is_valid(&at(1, 9, "abc"), Unit::Chars));
+        assert!(!lenient.is_valid(&at(0, 9, "abc"), Unit::Chars));
+        assert!(!lenient.is_valid(&at(4, 5, "aaa"), Unit::Chars));
+        assert_eq!(
+            lenient.check(&at(0, 4, "aaa"), Unit::Chars).unwrap_err(),
+            "neither position 0 nor 4 contains letter 'a'"
+        );
+
+        let strict = super::parse_policy("positions=strict").unwrap();
+        assert!(strict.is_valid(&at(1, 3, "abc"), Unit::Chars));
+        assert_eq!(
+            strict.check(&at(0, 1, "abc"), Unit::Chars).unwrap_err(),
+            "malformed: no position 0 in a password of 3 characters"
+        );
+        assert_eq!(
+            strict.check(&at(1, 4, "abc"), Unit::Chars).unwrap_err(),
+            "malformed: no position 4 in a password of 3 characters"
+        );
+        assert!(!strict.is_valid(&at(1, 1, ""), Unit::Chars));
+    }
+
+    #[test]
+    fn test_graphemes() {
+        // e followed by a combining acute accent, and a thumbs up with a
+        // skin tone modifier
+        let accented = "e\u{301}";
+        let thumbs_up = "\u{1F44D}\u{1F3FD}";
+        let password = format!("{}a{}e", accented, thumbs_up);
+        let rule = |floor: u32, ceil: u32, token: &str| Entry {
+            floor,
+            ceil,
+            token: token.to_string(),
+            password: password.clone(),
+        };
+        let check = |spec: &str, entry: &Entry, unit: Unit| {
+            super::parse_policy(spec).unwrap().is_valid(entry, unit)
+        };
+
+        assert!(check("count", &rule(2, 2, "e"), Unit::Chars));
+        assert!(check("count", &rule(1, 1, "e"), Unit::Graphemes));
+        assert!(check("count", &rule(1, 1, accented), Unit::Graphemes));
+
+        assert!(check("positions", &rule(2, 9, "a"), Unit::Graphemes));
+        assert!(!check("positions", &rule(2, 9, "a"), Unit::Chars));
+        assert!(check("positions", &rule(3, 4, thumbs_up), Unit::Graphemes));
+        assert!(!check(
+            "positions=strict",
+            &rule(3, 5, "e"),
+            Unit::Graphemes
+        ));
+        assert!(check("positions=strict", &rule(3, 5, "a"), Unit::Chars));
+
+        let entry = rule(1, 1, "a");
+        assert!(check("min-length=4", &entry, Unit::Graphemes));
+        assert!(!check("min-length=5", &entry, Unit::Graphemes));
+        assert!(check("min-length=6", &entry, Unit::Chars));
+        assert!(check("distinct=4", &entry, Unit::Graphemes));
+        assert!(check("class=[a-z]{3}", &entry, Unit::Graphemes));
+        assert!(check("class=[a-z]{3}", &entry, Unit::Chars));
+        assert!(check("forbid=\u{301}a", &entry, Unit::Graphemes));
+        assert!(!check("forbid=\u{301}a", &entry, Unit::Chars));
+
+        // messages count in the unit the policy counted in
+        let strict = super::parse_policy("positions=strict").unwrap();
+        assert_eq!(
+            strict.check(&rule(1, 5, "a"), Unit::Graphemes).unwrap_err(),
+            "malformed: no position 5 in a password of 4 graphemes"
+        );
+        let min_length = super::parse_policy("min-length=5").unwrap();
+        assert_eq!(
+            min_length.check(&entry, Unit::Graphemes).unwrap_err(),
+            "4 graphemes long, at least 5 required"
+        );
+
+        let policy = super::parse_policy("positions").unwrap();
+        assert_eq!(
+            policy.check(&rule(1, 4, thumbs_up), Unit::Graphemes),
+            Err(format!(
+                "neither position 1 nor 4 contains letter '{}'",
+                thumbs_up
+            ))
+        );
+    }
+
+    #[test]
+    fn test_character_class() {
+        let digits = super::parse_policy("class=[0-9]{2,}").unwrap();
+        assert!(digits.is_valid(&entry("a1b2"), Unit::Chars));
+        assert!(!digits.is_valid(&entry("a1b"), Unit::Chars));
+
+        let lowercase = super::parse_policy("class=[^a-z]{0}").unwrap();
+        assert!(lowercase.is_valid(&entry("abc"), Unit::Chars));
+        assert!(!lowercase.is_valid(&entry("aBc"), Unit::Chars));
+
+        let symbols = super::parse_policy("class=[\\]\\-_]{1,2}").unwrap();
+        assert!(symbols.is_valid(&entry("a-b"), Unit::Chars));
+        assert!(!symbols.is_valid(&entry("a-b]c_"), Unit::Chars));
+
+        let dash = super::parse_policy("class=[a-]").unwrap();
+        assert!(dash.is_valid(&entry("-"), Unit::Chars));
+
+        assert!(super::parse_policy("class=[0-9").is_err());
+        assert!(super::parse_policy("class=[9-0]").is_err());
+        assert!(super::parse_policy("class=[0-9]{3,1}").is_err());
+        assert!(super::parse_policy("class=[0-9]+").is_err());
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        for spec in &[
+            "count",
+            "positions",
+            "positions=strict",
+            "distinct=3",
+            "forbid=abc,xyz",
+            "min-length=8",
+            "class=[0-9]",
+            "class=[^a-z\\-]{0}",
+            "class=[A-Z]{2,}",
+            "class=[a-f]{1,3}",
+        ] {
+            assert_eq!(super::parse_policy(spec).unwrap().to_string(), *spec);
+        }
+
+        assert!(super::parse_policy("count=2").is_err());
+        assert!(super::parse_policy("positions=lenient").is_err());
+        assert!(super::parse_policy("distinct").is_err());
+        assert!(super::parse_policy("forbid=").is_err());
+        assert!(super::parse_policy("forbid=a,,b").is_err());
+        assert!(super::parse_policy("forbid=a,").is_err());
+        assert!(super::parse_policy("lowercase").is_err());
+    }
+}
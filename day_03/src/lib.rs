@@ -0,0 +1,131 @@
+mod overlay;
+mod slopes;
+mod terrain;
+mod traverse;
+
+use common::{debug, Error, Grid, Solution};
+
+pub use overlay::{overlay, path, Mark, Overlay, Style};
+pub use slopes::{extremes, rank_slopes, Ranked, Slope, TreeIndex};
+pub use terrain::{Collisions, Legend, Terrain};
+pub use traverse::{traverse, Step, Traversal, Wrap};
+
+fn render_map(map: &Grid<usize>, legend: &Legend) -> String {
+    map.render(|&terrain| legend.symbol(terrain))
+}
+
+/// read_map reads the map of the puzzle, with the default legend of open
+/// squares and trees
+pub fn read_map(content: &str) -> Result<Grid<usize>, Error> {
+    Legend::default().read_map(content)
+}
+
+/// count_collisions counts the squares of every terrain among those the
+/// sled goes over, and what they cost
+pub fn count_collisions(
+    map: &Grid<usize>,
+    legend: &Legend,
+    squares: impl IntoIterator<Item = (usize, usize)>,
+) -> Collisions {
+    let mut counts = vec![0; legend.terrains.len()];
+    let mut weighted = 0;
+    for square in squares {
+        let terrain = map[square];
+        counts[terrain] += 1;
+        weighted += u64::from(legend.cost(terrain));
+    }
+
+    Collisions { counts, weighted }
+}
+
+/// get_tree_collisions counts the squares of every terrain the sled goes
+/// over from the top left corner, the map repeating to the right
+pub fn get_tree_collisions(
+    map: &Grid<usize>,
+    legend: &Legend,
+    right: usize,
+    down: usize,
+) -> Collisions {
+    // the map repeats to the right, so only right modulo the width matters,
+    // and that fits in the isize of a step
+    let right = right % map.width().max(1);
+    let slope = Slope { right, down };
+    let squares = traverse(map, 0, 0, slope.into(), Wrap::Horizontal);
+    count_collisions(map, legend, squares)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<usize>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_map(content)
+    }
+
+    fn part_one(map: &Self::Input) -> Result<Self::PartOne, Error> {
+        let legend = Legend::default();
+        debug!("map:\n{}", render_map(map, &legend));
+
+        // part one
+        Ok(get_tree_collisions(map, &legend, 3, 1).weighted)
+    }
+
+    fn part_two(map: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // part two
+        let part_two_params = [[1, 1], [3, 1], [5, 1], [7, 1], [1, 2]];
+        let legend = Legend::default();
+        let part_two_counts: Vec<u64> = part_two_params
+            .iter()
+            .map(|x| get_tree_collisions(map, &legend, x[0], x[1]).weighted)
+            .collect();
+        debug!("part_two_counts: {:?}", part_two_counts);
+        Ok(part_two_counts.iter().product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Legend;
+
+    #[test]
+    fn test_get_tree_collisions() {
+        let map = super::read_map(include_str!("input_example")).unwrap();
+        let collisions = super::get_tree_collisions(&map, &Legend::default(), 3, 1);
+        assert_eq!(collisions.counts, vec![4, 7]);
+        assert_eq!(collisions.weighted, 7);
+
+        // a slope along the first row goes round it once, rather than taking
+        // as many steps as there are rows and meeting its tree twice
+        let map = super::read_map("#.\n..\n..\n").unwrap();
+        let collisions = super::get_tree_collisions(&map, &Legend::default(), 1, 0);
+        assert_eq!(collisions.counts, vec![1, 1]);
+
+        // a right past isize::MAX wraps like any other rather than turning
+        // into a step to the left
+        let map = super::read_map(include_str!("input_example")).unwrap();
+        let index = super::TreeIndex::new(&map, &Legend::default());
+        let right = usize::MAX;
+        let collisions = super::get_tree_collisions(&map, &Legend::default(), right, 1);
+        assert_eq!(
+            collisions.weighted,
+            index.collisions(super::Slope { right, down: 1 })
+        );
+        assert_eq!(collisions.weighted, 2);
+
+        let legend = Legend::parse(".=open:0,#=tree:1,^=rock:5,~=ice:0").unwrap();
+        let map = legend.read_map("..#~\n#~^.\n.^#.\n~..#\n").unwrap();
+        let collisions = super::get_tree_collisions(&map, &legend, 1, 1);
+        assert_eq!(collisions.counts, vec![1, 2, 0, 1]);
+        assert_eq!(collisions.weighted, 2);
+        let collisions = super::get_tree_collisions(&map, &legend, 2, 1);
+        assert_eq!(collisions.counts, vec![3, 0, 1, 0]);
+        assert_eq!(collisions.weighted, 5);
+        assert_eq!(
+            collisions.describe(&legend),
+            "3 open, 0 tree, 1 rock, 0 ice, cost 5"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{Legend, Slope, Wrap};
+use common::Grid;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Mark is what a square of the map shows once the path of the sled is
+/// drawn over it. Terrains that cost nothing to cross, like open squares,
+/// are ground, and those that do, like trees, are obstacles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Ground(char),
+    Obstacle(char),
+    /// ground the sled went over
+    Passed,
+    /// an obstacle the sled hit
+    Hit,
+    /// stands for copies of the map the sled jumped over without landing
+    Skipped,
+}
+
+impl fmt::Display for Mark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Mark::Ground(c) | Mark::Obstacle(c) => *c,
+            Mark::Passed => 'O',
+            Mark::Hit => 'X',
+            Mark::Skipped => '|',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// Overlay is the map with the path of the sled drawn over it, either on
+/// the map itself or on the copies of it the sled landed in, side by side
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    pub marks: Grid<Mark>,
+    /// the width of the original map, which the copies repeat
+    pub tile_width: usize,
+    /// which copies of the map are laid out, counting from 0 for the map
+    /// itself. A column of Mark::Skipped separates two that do not follow
+    /// each other. A steep slope can land further right than usize counts.
+    pub tiles: Vec<u128>,
+}
+
+/// Style is how an overlay is exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Text,
+    /// text coloured with ANSI escape codes for a terminal
+    Ansi,
+    Svg,
+}
+
+/// path lists the squares the sled goes over, following get_tree_collisions,
+/// with columns counted across the copies of the map rather than wrapped. The
+/// columns are u128 so that no slope that fits in a usize can overflow them.
+pub fn path<T>(map: &Grid<T>, slope: Slope) -> Vec<(usize, u128)> {
+    let step = Slope {
+        right: slope.right % map.width().max(1),
+        ..slope
+    };
+    crate::traverse(map, 0, 0, step.into(), Wrap::Horizontal)
+        .enumerate()
+        .map(|(steps, (row, _))| (row, steps as u128 * slope.right as u128))
+        .collect()
+}
+
+/// overlay draws the path of the sled down the slope over the map. With
+/// tiles, the copies of the map the sled landed in are laid side by side,
+/// leaving out those it jumped over so that a steep slope does not draw
+/// thousands of copies; without, the path is wrapped back onto the map
+/// itself.
+pub fn overlay(map: &Grid<usize>, legend: &Legend, slope: Slope, tiles: bool) -> Overlay {
+    let width = map.width().max(1) as u128;
+    let squares = path(map, slope);
+    let mut copies: Vec<u128> = if tiles {
+        squares.iter().map(|&(_, col)| col / width).collect()
+    } else {
+        vec![0]
+    };
+    // the path only ever goes right
+    copies.dedup();
+
+    // the column of the map behind every column of the overlay, None for
+    // the columns marking skipped copies, and where each copy starts
+    let mut columns: Vec<Option<usize>> = vec![];
+    let mut starts: HashMap<u128, usize> = HashMap::new();
+    for (index, &copy) in copies.iter().enumerate() {
+        if index > 0 && copy > copies[index - 1] + 1 {
+            columns.push(None);
+        }
+        starts.insert(copy, columns.len());
+        columns.extend((0..map.width()).map(Some));
+    }
+
+    let mut marks = Grid::from_fn(map.height(), columns.len(), |row, col| {
+        let terrain = match columns[col] {
+            Some(col) => map[(row, col)],
+            None => return Mark::Skipped,
+        };
+        if legend.cost(terrain) > 0 {
+            Mark::Obstacle(legend.symbol(terrain))
+        } else {
+            Mark::Ground(legend.symbol(terrain))
+        }
+    });
+    for (row, col) in squares {
+        let copy = if tiles { col / width } else { 0 };
+        let col = starts[&copy] + (col % width) as usize;
+        if let Some(mark) = marks.get_mut(row, col) {
+            *mark = match mark {
+                Mark::Ground(_) | Mark::Passed => Mark::Passed,
+                Mark::Obstacle(_) | Mark::Hit => Mark::Hit,
+                Mark::Skipped => Mark::Skipped,
+            };
+        }
+    }
+
+    Overlay {
+        marks,
+        tile_width: map.width(),
+        tiles: copies,
+    }
+}
+
+/// CELL is the side of a square in SVG pixels
+const CELL: usize = 8;
+
+impl Overlay {
+    /// export renders the overlay in the given style. ANSI output colours
+    /// obstacles green, the path blue and collisions red; SVG draws a square
+    /// per obstacle and per step of the path, a dashed line where copies of
+    /// the map meet and a grey band where copies were skipped.
+    pub fn export(&self, style: Style) -> String {
+        match style {
+            Style::Text => self.marks.to_string(),
+            Style::Ansi => {
+                let ansi = |mark: &Mark| {
+                    let colour = match mark {
+                        Mark::Ground(_) | Mark::Skipped => return mark.to_string(),
+                        Mark::Obstacle(_) => "32",
+                        Mark::Passed => "1;34",
+                        Mark::Hit => "1;31",
+                    };
+                    format!("\x1b[{}m{}\x1b[0m", colour, mark)
+                };
+                let lines: Vec<String> = self
+                    .marks
+                    .rows()
+                    .map(|row| row.iter().map(ansi).collect())
+                    .collect();
+                lines.join("\n")
+            }
+            Style::Svg => self.svg(),
+        }
+    }
+
+    fn svg(&self) -> String {
+        let (width, height) = (self.marks.width() * CELL, self.marks.height() * CELL);
+        let mut lines = vec![
+            format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+                 viewBox=\"0 0 {} {}\">",
+                width, height, width, height
+            ),
+            format!(
+                "  <rect width=\"{}\" height=\"{}\" fill=\"#ffffff\"/>",
+                width, height
+            ),
+        ];
+        for (row, col) in self.marks.positions() {
+            let fill = match self.marks[(row, col)] {
+                Mark::Ground(_) => continue,
+                Mark::Obstacle(_) => "#2e7d32",
+                Mark::Passed => "#64b5f6",
+                Mark::Hit => "#e53935",
+                Mark::Skipped => "#e0e0e0",
+            };
+            lines.push(format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                col * CELL,
+                row * CELL,
+                CELL,
+                CELL,
+                fill
+            ));
+        }
+        let mut col = 0;
+        for (index, &copy) in self.tiles.iter().enumerate() {
+            if index > 0 && copy == self.tiles[index - 1] + 1 {
+                let x = col * CELL;
+                lines.push(format!(
+                    "  <line x1=\"{}\" y1=\"0\" x2=\"{}\" y2=\"{}\" stroke=\"#9e9e9e\" \
+                     stroke-dasharray=\"4 4\"/>",
+                    x, x, height
+                ));
+            } else if index > 0 {
+                // the column of skipped marks
+                col += 1;
+            }
+            col += self.tile_width;
+        }
+        lines.push(String::from("</svg>"));
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mark, Style};
+    use crate::{Legend, Slope};
+
+    fn map() -> common::Grid<usize> {
+        crate::read_map(include_str!("input_example")).unwrap()
+    }
+
+    #[test]
+    fn test_overlay() {
+        let slope = Slope { right: 3, down: 1 };
+        let overlay = super::overlay(&map(), &Legend::default(), slope, true);
+        let text = overlay.export(Style::Text);
+        let lines: Vec<&str> = text.lines().collect();
+        // the opening rows of the example in the puzzle
+        assert_eq!(lines[0], "O.##.........##.........##.......");
+        assert_eq!(lines[1], "#..O#...#..#...#...#..#...#...#..");
+        assert_eq!(lines[2], ".#....X..#..#....#..#..#....#..#.");
+        assert_eq!(lines[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+        assert_eq!(overlay.marks.width(), 33);
+        let hits = overlay.marks.iter().filter(|&&m| m == Mark::Hit).count();
+        assert_eq!(hits, 7);
+
+        let wrapped = super::overlay(&map(), &Legend::default(), slope, false);
+        assert_eq!(wrapped.marks.width(), 11);
+        assert_eq!(
+            wrapped.export(Style::Text).lines().nth(3),
+            Some("..#.#...#O#")
+        );
+        let hits = wrapped.marks.iter().filter(|&&m| m == Mark::Hit).count();
+        assert_eq!(hits, 7);
+    }
+
+    #[test]
+    fn test_skipped_tiles() {
+        // every step lands thousands of copies further right
+        let slope = Slope {
+            right: 100_000,
+            down: 1,
+        };
+        let legend = Legend::default();
+        let overlay = super::overlay(&map(), &legend, slope, true);
+        assert_eq!(overlay.tiles.len(), 11);
+        assert_eq!(overlay.tiles[1], 9090);
+        assert_eq!(overlay.marks.width(), 11 * 11 + 10);
+        let text = overlay.export(Style::Text);
+        assert!(text.starts_with("O.##.......|..##......."));
+        let hits = overlay.marks.iter().filter(|&&m| m == Mark::Hit).count() as u64;
+        assert_eq!(
+            hits,
+            crate::get_tree_collisions(&map(), &legend, 100_000, 1).weighted
+        );
+
+        let svg = overlay.export(Style::Svg);
+        assert!(svg.contains("fill=\"#e0e0e0\""));
+        assert!(!svg.contains("stroke-dasharray"));
+    }
+
+    #[test]
+    fn test_huge_slope() {
+        // steps times right is far past usize::MAX by the last row
+        let right = 2_000_000_000_000_000_000;
+        let slope = Slope { right, down: 1 };
+        let legend = Legend::default();
+        let collisions = crate::get_tree_collisions(&map(), &legend, right, 1).weighted;
+        for &tiles in &[false, true] {
+            let overlay = super::overlay(&map(), &legend, slope, tiles);
+            let hits = overlay.marks.iter().filter(|&&m| m == Mark::Hit).count() as u64;
+            assert_eq!(hits, collisions);
+        }
+        let overlay = super::overlay(&map(), &legend, slope, true);
+        assert_eq!(overlay.tiles[10], 10 * right as u128 / 11);
+    }
+
+    #[test]
+    fn test_export() {
+        let map = crate::read_map("#.\n..\n").unwrap();
+        let overlay = super::overlay(&map, &Legend::default(), Slope { right: 2, down: 1 }, true);
+        assert_eq!(overlay.export(Style::Text), "X.#.\n..O.");
+        assert_eq!(
+            overlay.export(Style::Ansi),
+            "\x1b[1;31mX\x1b[0m.\x1b[32m#\x1b[0m.\n..\x1b[1;34mO\x1b[0m."
+        );
+
+        let svg = overlay.export(Style::Svg);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"32\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"8\" height=\"8\" fill=\"#e53935\"/>"));
+        assert!(svg.contains("<rect x=\"16\" y=\"8\" width=\"8\" height=\"8\" fill=\"#64b5f6\"/>"));
+        assert!(svg.contains("<line x1=\"16\" y1=\"0\" x2=\"16\" y2=\"16\""));
+        assert!(svg.ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{Legend, Terrain};
+use common::Grid;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Slope is a trajectory down the map: so many squares right for so many
+/// down, the map repeating to the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+/// TreeIndex holds the squares of every row as a bitset, one for each
+/// terrain that costs something to cross, so that a sweep over many slopes
+/// tests squares with a shift and a mask, and skips the rows where crossing
+/// costs nothing altogether
+pub struct TreeIndex {
+    width: usize,
+    /// the cost of every costly terrain along with its bitset rows
+    layers: Vec<(u64, Vec<Vec<u64>>)>,
+    costly_rows: Vec<bool>,
+}
+
+impl TreeIndex {
+    pub fn new(map: &Grid<usize>, legend: &Legend) -> TreeIndex {
+        let words = map.width().div_ceil(64);
+        let mut layer_of = vec![None; legend.terrains.len()];
+        let mut layers = vec![];
+        for (terrain, &Terrain { cost, .. }) in legend.terrains.iter().enumerate() {
+            if cost > 0 {
+                layer_of[terrain] = Some(layers.len());
+                layers.push((u64::from(cost), vec![vec![0u64; words]; map.height()]));
+            }
+        }
+
+        let mut costly_rows = vec![false; map.height()];
+        for (row, col) in map.positions() {
+            if let Some(layer) = layer_of[map[(row, col)]] {
+                layers[layer].1[row][col / 64] |= 1 << (col % 64);
+                costly_rows[row] = true;
+            }
+        }
+
+        TreeIndex {
+            width: map.width(),
+            layers,
+            costly_rows,
+        }
+    }
+
+    fn cost_at(&self, row: usize, col: usize) -> u64 {
+        self.layers
+            .iter()
+            .filter(|(_, rows)| rows[row][col / 64] >> (col % 64) & 1 == 1)
+            .map(|&(cost, _)| cost)
+            .sum()
+    }
+
+    /// collisions adds up the cost of the squares met along the slope,
+    /// stopping where get_tree_collisions does: past the last row, or back
+    /// on the first square for a slope going along it
+    pub fn collisions(&self, slope: Slope) -> u64 {
+        let height = self.costly_rows.len();
+        if self.width == 0 || height == 0 {
+            return 0;
+        }
+
+        let right = slope.right % self.width;
+        let mut cost = 0;
+        let mut col = 0;
+        let mut row = 0;
+        loop {
+            if self.costly_rows[row] {
+                cost += self.cost_at(row, col);
+            }
+            col = (col + right) % self.width;
+            row += slope.down;
+            if row >= height || (row, col) == (0, 0) {
+                break;
+            }
+        }
+
+        cost
+    }
+}
+
+/// Ranked is a slope along with its place in a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranked {
+    /// 1-indexed, slopes with as many collisions sharing a rank
+    pub rank: usize,
+    pub slope: Slope,
+    /// the cost of the squares met, which is the number of trees with the
+    /// default legend
+    pub collisions: u64,
+}
+
+/// rank_slopes evaluates every slope within the bounds and orders them
+/// from the fewest collisions to the most, then by slope. Slopes going as
+/// far right modulo the width of the map meet the same trees, so each of
+/// those is only walked once.
+pub fn rank_slopes(
+    map: &Grid<usize>,
+    legend: &Legend,
+    rights: RangeInclusive<usize>,
+    downs: RangeInclusive<usize>,
+) -> Vec<Ranked> {
+    let index = TreeIndex::new(map, legend);
+    let width = map.width().max(1);
+
+    let mut walked: HashMap<Slope, u64> = HashMap::new();
+    let mut slopes = vec![];
+    for down in downs {
+        for right in rights.clone() {
+            let slope = Slope { right, down };
+            let equivalent = Slope {
+                right: right % width,
+                down,
+            };
+            let collisions = *walked
+                .entry(equivalent)
+                .or_insert_with(|| index.collisions(equivalent));
+            slopes.push((collisions, slope));
+        }
+    }
+    slopes.sort_unstable();
+
+    let mut ranked: Vec<Ranked> = vec![];
+    for (position, (collisions, slope)) in slopes.into_iter().enumerate() {
+        let rank = match ranked.last() {
+            Some(previous) if previous.collisions == collisions => previous.rank,
+            _ => position + 1,
+        };
+        ranked.push(Ranked {
+            rank,
+            slope,
+            collisions,
+        });
+    }
+
+    ranked
+}
+
+/// extremes picks the slopes tied for the fewest collisions and those tied
+/// for the most out of a ranking
+pub fn extremes(ranked: &[Ranked]) -> (Vec<&Ranked>, Vec<&Ranked>) {
+    let fewest = match ranked.first() {
+        Some(first) => first.collisions,
+        None => return (vec![], vec![]),
+    };
+    let most = ranked.last().map_or(fewest, |last| last.collisions);
+
+    (
+        ranked.iter().filter(|r| r.collisions == fewest).collect(),
+        ranked.iter().filter(|r| r.collisions == most).collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Slope, TreeIndex};
+    use crate::Legend;
+
+    fn map() -> common::Grid<usize> {
+        crate::read_map(include_str!("input_example")).unwrap()
+    }
+
+    #[test]
+    fn test_collisions() {
+        let map = map();
+        let legend = Legend::default();
+        let index = TreeIndex::new(&map, &legend);
+        for right in 0..=25 {
+            for down in 0..=12 {
+                assert_eq!(
+                    index.collisions(Slope { right, down }),
+                    crate::get_tree_collisions(&map, &legend, right, down).weighted,
+                    "right {}, down {}",
+                    right,
+                    down
+                );
+            }
+        }
+
+        let wide =
+            crate::read_map(&format!("{}#\n{}#.\n", ".".repeat(99), ".".repeat(98))).unwrap();
+        let index = TreeIndex::new(&wide, &legend);
+        assert_eq!(index.collisions(Slope { right: 0, down: 1 }), 0);
+        assert_eq!(
+            index.collisions(Slope {
+                right: 100,
+                down: 1
+            }),
+            0
+        );
+        assert_eq!(index.collisions(Slope { right: 98, down: 1 }), 1);
+        assert_eq!(index.collisions(Slope { right: 99, down: 0 }), 1);
+        assert_eq!(
+            index.collisions(Slope {
+                right: 100,
+                down: 0
+            }),
+            0
+        );
+
+        let legend = Legend::parse(".=open:0,#=tree:1,^=rock:5,~=ice:2,*=snow:0").unwrap();
+        let rugged = legend
+            .read_map("..#~^*.\n~^.#..*\n#..^~.#\n.*~.#^.\n^#..~.*\n")
+            .unwrap();
+        let index = TreeIndex::new(&rugged, &legend);
+        for right in 0..=10 {
+            for down in 1..=3 {
+                assert_eq!(
+                    index.collisions(Slope { right, down }),
+                    crate::get_tree_collisions(&rugged, &legend, right, down).weighted
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_slopes() {
+        let ranked = super::rank_slopes(&map(), &Legend::default(), 1..=7, 1..=2);
+        assert_eq!(ranked.len(), 14);
+        let first = &ranked[0];
+        assert_eq!((first.rank, first.collisions), (1, 0));
+        assert_eq!(first.slope, Slope { right: 5, down: 2 });
+
+        let (fewest, most) = super::extremes(&ranked);
+        let slopes = |ranked: Vec<&super::Ranked>| -> Vec<(usize, usize, u64)> {
+            ranked
+                .iter()
+                .map(|r| (r.slope.right, r.slope.down, r.collisions))
+                .collect()
+        };
+        assert_eq!(slopes(fewest), vec![(5, 2, 0)]);
+        assert_eq!(slopes(most), vec![(3, 1, 7)]);
+
+        // four slopes meet a single tree, and share the second rank
+        let ranks: Vec<usize> = ranked.iter().map(|r| r.rank).take(6).collect();
+        assert_eq!(ranks, vec![1, 2, 2, 2, 2, 6]);
+        assert!(ranked
+            .windows(2)
+            .all(|w| w[0].collisions <= w[1].collisions));
+
+        assert_eq!(super::extremes(&[]), (vec![], vec![]));
+    }
+}
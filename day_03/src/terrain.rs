@@ -0,0 +1,171 @@
+use common::{Error, Grid};
+use std::fmt;
+
+/// Terrain is a kind of square of the map, and what crossing it costs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Terrain {
+    pub symbol: char,
+    pub name: String,
+    pub cost: u32,
+}
+
+/// Legend lists the terrains a map may contain. A map read with a legend
+/// holds the index of the terrain of every square, as a Grid<usize>.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Legend {
+    pub terrains: Vec<Terrain>,
+}
+
+impl Default for Legend {
+    /// the map of the puzzle: open squares, and trees counting one each
+    fn default() -> Legend {
+        Legend {
+            terrains: vec![
+                Terrain {
+                    symbol: '.',
+                    name: String::from("open"),
+                    cost: 0,
+                },
+                Terrain {
+                    symbol: '#',
+                    name: String::from("tree"),
+                    cost: 1,
+                },
+            ],
+        }
+    }
+}
+
+impl Legend {
+    /// parse reads a legend written as comma separated `symbol=name:cost`
+    /// entries, as in `.=open:0,#=tree:1,^=rock:5,~=ice:2`
+    pub fn parse(spec: &str) -> Result<Legend, String> {
+        let mut terrains: Vec<Terrain> = vec![];
+        let mut rest = spec;
+        loop {
+            let invalid = || format!("invalid terrain {:?}, expected symbol=name:cost", rest);
+            let mut chars = rest.chars();
+            let symbol = chars.next().ok_or_else(invalid)?;
+            let entry = chars.as_str().strip_prefix('=').ok_or_else(invalid)?;
+            let (entry, next) = match entry.split_once(',') {
+                Some((entry, next)) => (entry, Some(next)),
+                None => (entry, None),
+            };
+            let (name, cost) = entry.split_once(':').ok_or_else(invalid)?;
+            let cost = cost.parse().map_err(|_| invalid())?;
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            if terrains.iter().any(|terrain| terrain.symbol == symbol) {
+                return Err(format!("terrain {:?} is listed twice", symbol));
+            }
+            terrains.push(Terrain {
+                symbol,
+                name: name.to_string(),
+                cost,
+            });
+
+            match next {
+                Some(next) => rest = next,
+                None => break,
+            }
+        }
+
+        Ok(Legend { terrains })
+    }
+
+    /// read_map reads a map of the terrains of the legend, any other
+    /// character being an error
+    pub fn read_map(&self, content: &str) -> Result<Grid<usize>, Error> {
+        Grid::parse(content, |c| {
+            self.terrains.iter().position(|terrain| terrain.symbol == c)
+        })
+    }
+
+    /// cost tells what crossing a square of the terrain at index costs
+    pub fn cost(&self, terrain: usize) -> u32 {
+        self.terrains[terrain].cost
+    }
+
+    pub fn symbol(&self, terrain: usize) -> char {
+        self.terrains[terrain].symbol
+    }
+}
+
+impl fmt::Display for Legend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, terrain) in self.terrains.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}:{}", terrain.symbol, terrain.name, terrain.cost)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collisions is what a run down the map went through: how many squares of
+/// every terrain of the legend, by index, and their total cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collisions {
+    pub counts: Vec<u32>,
+    pub weighted: u64,
+}
+
+impl Collisions {
+    /// describe lists the count of every terrain by name, then the total
+    pub fn describe(&self, legend: &Legend) -> String {
+        let counts: Vec<String> = legend
+            .terrains
+            .iter()
+            .zip(&self.counts)
+            .map(|(terrain, count)| format!("{} {}", count, terrain.name))
+            .collect();
+        format!("{}, cost {}", counts.join(", "), self.weighted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Legend;
+
+    #[test]
+    fn test_parse() {
+        let legend = Legend::parse(".=open:0,#=tree:1,^=rock:5,~=ice:2").unwrap();
+        assert_eq!(legend.terrains.len(), 4);
+        assert_eq!(legend.terrains[2].name, "rock");
+        assert_eq!(legend.cost(2), 5);
+        assert_eq!(legend.to_string(), ".=open:0,#=tree:1,^=rock:5,~=ice:2");
+        assert_eq!(
+            Legend::parse(&Legend::default().to_string()).unwrap(),
+            Legend::default()
+        );
+
+        // symbols may be the separators themselves
+        let legend = Legend::parse(",=comma:1,==equals:2,:=colon:3").unwrap();
+        let symbols: String = legend.terrains.iter().map(|t| t.symbol).collect();
+        assert_eq!(symbols, ",=:");
+
+        assert!(Legend::parse("").is_err());
+        assert!(Legend::parse(".=open").is_err());
+        assert!(Legend::parse(".=open:x").is_err());
+        assert!(Legend::parse(".=:1").is_err());
+        assert!(Legend::parse(".open:1").is_err());
+        assert!(Legend::parse(".=open:0,").is_err());
+        assert!(Legend::parse(".=open:0,.=ice:1").is_err());
+    }
+
+    #[test]
+    fn test_read_map() {
+        let legend = Legend::parse(".=open:0,#=tree:1,^=rock:5").unwrap();
+        let map = legend.read_map(".#^\n^..\n").unwrap();
+        assert_eq!(
+            map.iter().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 2, 0, 0]
+        );
+
+        let error = Legend::default().read_map(".#^\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 3: unknown character '^'");
+    }
+}
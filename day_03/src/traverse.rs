@@ -0,0 +1,244 @@
+use crate::Slope;
+use common::Grid;
+
+/// Wrap is what happens when the sled goes past an edge of the map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wrap {
+    /// the run ends at any edge
+    None,
+    /// the map repeats to the left and right, as in the puzzle, and the run
+    /// ends at the top or the bottom
+    Horizontal,
+    /// the map repeats in every direction, like the surface of a torus
+    Torus,
+}
+
+/// Step is how the sled moves down the map, either number being negative to
+/// go left or up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// jump right and down squares at once, skipping the rows in between
+    Whole { right: isize, down: isize },
+    /// go one row at a time, moving right squares across for every down
+    /// rows: 1 right per 3 down stays in the same column for three rows. The
+    /// column is kept as an exact fraction and rounded toward the starting
+    /// column, so going left mirrors going right. With down 0 this is the
+    /// same as a whole step.
+    Fraction { right: isize, down: isize },
+}
+
+impl From<Slope> for Step {
+    fn from(slope: Slope) -> Step {
+        Step::Whole {
+            right: slope.right as isize,
+            down: slope.down as isize,
+        }
+    }
+}
+
+/// Traversal is the iterator returned by traverse
+#[derive(Debug, Clone)]
+pub struct Traversal {
+    width: isize,
+    height: isize,
+    step: Step,
+    wrap: Wrap,
+    start: (isize, isize),
+    /// the next square, None once the run is over
+    position: Option<(isize, isize)>,
+    /// for fractional steps, how far across the next column the sled is, in
+    /// steps of 1/down
+    carry: isize,
+}
+
+/// traverse lists the squares the sled goes over from a starting square,
+/// that one included. The run ends when the sled leaves the map, or when it
+/// would come back to the starting square the way it started, since it
+/// would only go over the same squares again. A horizontal slope on a
+/// wrapping map thus goes round its row once.
+pub fn traverse<T>(map: &Grid<T>, row: usize, col: usize, step: Step, wrap: Wrap) -> Traversal {
+    let start = (row as isize, col as isize);
+    Traversal {
+        width: map.width() as isize,
+        height: map.height() as isize,
+        step,
+        wrap,
+        start,
+        position: map.get(row, col).map(|_| start),
+        carry: 0,
+    }
+}
+
+impl Traversal {
+    /// advance moves the sled once from the square it is on, which may take
+    /// it off the map
+    fn advance(&mut self, (row, col): (isize, isize)) -> (isize, isize) {
+        let (right, down) = match self.step {
+            Step::Fraction { right, down } if down != 0 => {
+                self.carry += right;
+                let across = self.carry / down.abs();
+                self.carry -= across * down.abs();
+                (across, down.signum())
+            }
+            Step::Fraction { right, down } | Step::Whole { right, down } => (right, down),
+        };
+
+        let (mut row, mut col) = (row + down, col + right);
+        if self.wrap != Wrap::None {
+            col = col.rem_euclid(self.width);
+        }
+        if self.wrap == Wrap::Torus {
+            row = row.rem_euclid(self.height);
+        }
+
+        (row, col)
+    }
+}
+
+impl Iterator for Traversal {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.position?;
+        let next = self.advance((row, col));
+        let on_map = (0..self.height).contains(&next.0) && (0..self.width).contains(&next.1);
+        self.position = if on_map && (next != self.start || self.carry != 0) {
+            Some(next)
+        } else {
+            None
+        };
+
+        Some((row as usize, col as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Step, Wrap};
+    use common::Grid;
+
+    fn map() -> Grid<usize> {
+        Grid::from_fn(4, 5, |_, _| 0)
+    }
+
+    fn run(row: usize, col: usize, step: Step, wrap: Wrap) -> Vec<(usize, usize)> {
+        super::traverse(&map(), row, col, step, wrap).collect()
+    }
+
+    #[test]
+    fn test_horizontal() {
+        let step = Step::Whole { right: 3, down: 1 };
+        assert_eq!(
+            run(0, 0, step, Wrap::Horizontal),
+            vec![(0, 0), (1, 3), (2, 1), (3, 4)]
+        );
+        // down 2 stops past the last row rather than after so many steps
+        let step = Step::Whole { right: 1, down: 2 };
+        assert_eq!(run(0, 0, step, Wrap::Horizontal), vec![(0, 0), (2, 1)]);
+
+        // along a row, once round
+        let step = Step::Whole { right: 2, down: 0 };
+        let squares = run(1, 0, step, Wrap::Horizontal);
+        assert_eq!(squares, vec![(1, 0), (1, 2), (1, 4), (1, 1), (1, 3)]);
+        let step = Step::Whole { right: 5, down: 0 };
+        assert_eq!(run(1, 0, step, Wrap::Horizontal), vec![(1, 0)]);
+        let step = Step::Whole { right: 0, down: 0 };
+        assert_eq!(run(1, 0, step, Wrap::Horizontal), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn test_torus() {
+        let step = Step::Whole { right: 1, down: 1 };
+        let squares = run(0, 0, step, Wrap::Torus);
+        // 4 rows and 5 columns come back together after 20 steps
+        assert_eq!(squares.len(), 20);
+        assert_eq!(squares[4], (0, 4));
+        assert_eq!(squares[19], (3, 4));
+
+        let step = Step::Whole { right: 0, down: 2 };
+        assert_eq!(run(1, 3, step, Wrap::Torus), vec![(1, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn test_no_wrap() {
+        let step = Step::Whole { right: 2, down: 1 };
+        assert_eq!(run(0, 0, step, Wrap::None), vec![(0, 0), (1, 2), (2, 4)]);
+        let step = Step::Whole { right: 1, down: 0 };
+        assert_eq!(run(3, 2, step, Wrap::None), vec![(3, 2), (3, 3), (3, 4)]);
+
+        // starting off the map goes nowhere
+        assert_eq!(run(4, 0, step, Wrap::Torus), vec![]);
+        assert_eq!(run(0, 5, step, Wrap::Torus), vec![]);
+    }
+
+    #[test]
+    fn test_negative() {
+        // left and up from the bottom right corner
+        let step = Step::Whole {
+            right: -1,
+            down: -1,
+        };
+        assert_eq!(
+            run(3, 4, step, Wrap::None),
+            vec![(3, 4), (2, 3), (1, 2), (0, 1)]
+        );
+        let step = Step::Whole { right: -3, down: 1 };
+        assert_eq!(
+            run(0, 0, step, Wrap::Horizontal),
+            vec![(0, 0), (1, 2), (2, 4), (3, 1)]
+        );
+        // going up from the top only leaves the map without vertical wrap
+        let step = Step::Whole { right: 1, down: -1 };
+        assert_eq!(run(0, 0, step, Wrap::Horizontal), vec![(0, 0)]);
+        let squares = run(0, 0, step, Wrap::Torus);
+        assert_eq!(&squares[..3], &[(0, 0), (3, 1), (2, 2)]);
+        assert_eq!(squares.len(), 20);
+    }
+
+    #[test]
+    fn test_fraction() {
+        let step = Step::Fraction { right: 1, down: 3 };
+        assert_eq!(
+            run(0, 0, step, Wrap::Horizontal),
+            vec![(0, 0), (1, 0), (2, 0), (3, 1)]
+        );
+        // going left mirrors going right
+        let step = Step::Fraction { right: -1, down: 3 };
+        assert_eq!(
+            run(0, 4, step, Wrap::None),
+            vec![(0, 4), (1, 4), (2, 4), (3, 3)]
+        );
+        // 5 right per 2 down goes 2 then 3 across
+        let step = Step::Fraction { right: 5, down: 2 };
+        assert_eq!(
+            run(0, 0, step, Wrap::Horizontal),
+            vec![(0, 0), (1, 2), (2, 0), (3, 2)]
+        );
+        // 2 per 6 is 1 per 3, where whole steps would skip rows
+        assert_eq!(
+            run(0, 0, Step::Fraction { right: 2, down: 6 }, Wrap::Horizontal),
+            run(0, 0, Step::Fraction { right: 1, down: 3 }, Wrap::Horizontal)
+        );
+
+        // the run only ends back at the start once the carry is used up too:
+        // 1 per 5 down on the torus is back on the first square after 4
+        // rows, but 4/5 of the way to the next column
+        let step = Step::Fraction { right: 1, down: 5 };
+        let squares = run(0, 0, step, Wrap::Torus);
+        assert_eq!(squares[4], (0, 0));
+        assert_eq!(squares.len(), 100);
+        let step = Step::Fraction { right: 1, down: 2 };
+        let squares = run(0, 0, step, Wrap::Torus);
+        assert_eq!(squares.len(), 20);
+        let step = Step::Fraction {
+            right: -1,
+            down: -2,
+        };
+        let squares = run(0, 0, step, Wrap::Torus);
+        assert_eq!(&squares[..4], &[(0, 0), (3, 0), (2, 4), (1, 4)]);
+        assert_eq!(squares.len(), 20);
+
+        let step = Step::Fraction { right: 2, down: 0 };
+        assert_eq!(run(0, 0, step, Wrap::None), vec![(0, 0), (0, 2), (0, 4)]);
+    }
+}
@@ -1,9 +1,7 @@
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
+use common::{input, Error, Solution};
 
 #[derive(Debug)]
-struct Passport {
+pub struct Passport {
     byr: Option<String>,
     iyr: Option<String>,
     eyr: Option<String>,
@@ -20,7 +18,7 @@ impl Passport {
         match &self.byr {
             Some(year) => {
                 let year: u32 = year.to_string().parse().unwrap_or_default();
-                if year < 1920 || year > 2002 {
+                if !(1920..=2002).contains(&year) {
                     return false;
                 }
             }
@@ -31,7 +29,7 @@ impl Passport {
         match &self.iyr {
             Some(year) => {
                 let year: u32 = year.to_string().parse().unwrap_or_default();
-                if year < 2010 || year > 2020 {
+                if !(2010..=2020).contains(&year) {
                     return false;
                 }
             }
@@ -42,7 +40,7 @@ impl Passport {
         match &self.eyr {
             Some(year) => {
                 let year: u32 = year.to_string().parse().unwrap_or_default();
-                if year < 2020 || year > 2030 {
+                if !(2020..=2030).contains(&year) {
                     return false;
                 }
             }
@@ -66,7 +64,7 @@ impl Passport {
                         .collect::<String>()
                         .parse()
                         .unwrap_or_default();
-                    if number < 150 || number > 193 {
+                    if !(150..=193).contains(&number) {
                         return false;
                     }
                 }
@@ -81,7 +79,7 @@ impl Passport {
                         .collect::<String>()
                         .parse()
                         .unwrap_or_default();
-                    if number < 59 || number > 76 {
+                    if !(59..=76).contains(&number) {
                         return false;
                     }
                 }
@@ -139,31 +137,11 @@ impl Passport {
     }
 }
 
-fn read_passports(filename: String) -> Vec<Passport> {
-    // open file
-    let path = Path::new(&filename);
-    let path_display = path.display();
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("could not open {}: {}", path_display, why),
-        Ok(file) => file,
-    };
-
-    // read file contents
-    let mut content = String::new();
-    if let Err(why) = file.read_to_string(&mut content) {
-        panic!("could not read {}: {}", path_display, why)
-    }
-
-    // pre-process
-    // build `lines` which is vector of line passports
-    let lines: Vec<&str> = content.split("\n\n").collect();
-    let lines: Vec<String> = lines.iter().map(|s| s.trim().replace("\n", " ")).collect();
-
-    // process
-    // build `passports` which is vector of struct Passport
+fn read_passports(content: &str) -> Result<Vec<Passport>, Error> {
+    // build `passports` which is vector of struct Passport,
+    // one per group of lines
     let mut passports: Vec<Passport> = vec![];
-    for line in lines {
-        let fields: Vec<&str> = line.split(' ').collect();
+    for group in input::groups(content) {
         let mut new_passport = Passport {
             byr: None,
             iyr: None,
@@ -175,55 +153,74 @@ fn read_passports(filename: String) -> Vec<Passport> {
             cid: None,
         };
 
-        for f in fields {
-            let kv_pair: Vec<&str> = f.split(':').collect();
-            let value = Some(kv_pair[1].to_string());
-            match kv_pair[0] {
-                "byr" => new_passport.byr = value,
-                "iyr" => new_passport.iyr = value,
-                "eyr" => new_passport.eyr = value,
-                "hgt" => new_passport.hgt = value,
-                "hcl" => new_passport.hcl = value,
-                "ecl" => new_passport.ecl = value,
-                "pid" => new_passport.pid = value,
-                "cid" => new_passport.cid = value,
-                _ => {}
+        for (line_number, line) in group {
+            for f in line.split_whitespace() {
+                let (key, value) = match f.split_once(':') {
+                    Some(kv_pair) => kv_pair,
+                    None => {
+                        let message = format!("expected key:value field, found {:?}", f);
+                        return Err(Error::at(line_number, line, f, message));
+                    }
+                };
+                let value = Some(value.to_string());
+                match key {
+                    "byr" => new_passport.byr = value,
+                    "iyr" => new_passport.iyr = value,
+                    "eyr" => new_passport.eyr = value,
+                    "hgt" => new_passport.hgt = value,
+                    "hcl" => new_passport.hcl = value,
+                    "ecl" => new_passport.ecl = value,
+                    "pid" => new_passport.pid = value,
+                    "cid" => new_passport.cid = value,
+                    _ => {}
+                }
             }
         }
         passports.push(new_passport);
     }
 
-    passports
+    Ok(passports)
 }
 
-fn main() {
-    let filename = String::from("./src/input");
-    let passports = read_passports(filename);
-
-    // part one
-    let mut valid_count = 0;
-    for p in &passports {
-        if p.byr.is_some()
-            && p.iyr.is_some()
-            && p.eyr.is_some()
-            && p.hgt.is_some()
-            && p.hcl.is_some()
-            && p.ecl.is_some()
-            && p.pid.is_some()
-        {
-            valid_count += 1;
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_passports(content)
+    }
+
+    fn part_one(passports: &Self::Input) -> Result<Self::PartOne, Error> {
+        // part one
+        let mut valid_count = 0;
+        for p in passports {
+            if p.byr.is_some()
+                && p.iyr.is_some()
+                && p.eyr.is_some()
+                && p.hgt.is_some()
+                && p.hcl.is_some()
+                && p.ecl.is_some()
+                && p.pid.is_some()
+            {
+                valid_count += 1;
+            }
         }
+
+        Ok(valid_count)
     }
-    println!("==result==");
-    println!("{}", valid_count);
-
-    // part two
-    let mut valid_count = 0;
-    for p in &passports {
-        if p.is_valid() {
-            valid_count += 1;
+
+    fn part_two(passports: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // part two
+        let mut valid_count = 0;
+        for p in passports {
+            if p.is_valid() {
+                valid_count += 1;
+            }
         }
+
+        Ok(valid_count)
     }
-    println!("==result part two==");
-    println!("{}", valid_count);
 }
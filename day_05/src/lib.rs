@@ -0,0 +1,137 @@
+use common::{input, Error, Solution};
+
+fn read_boarding_passes(content: &str) -> Result<Vec<String>, Error> {
+    // process into vec of strings, checking each one is a valid code
+    // of 7 row characters followed by 3 col characters
+    let mut lines: Vec<String> = vec![];
+    for (line_number, line) in input::lines(content) {
+        let code = line.trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        for (index, c) in code.chars().enumerate() {
+            let valid = match index {
+                0..=6 => c == 'F' || c == 'B',
+                7..=9 => c == 'L' || c == 'R',
+                _ => false,
+            };
+            if !valid {
+                let message = format!("unexpected character {:?} in boarding pass", c);
+                return Err(Error::parse(line_number, index + 1, line, message));
+            }
+        }
+        if code.len() != 10 {
+            let message = format!("boarding pass has {} characters, expected 10", code.len());
+            return Err(Error::at(line_number, line, code, message));
+        }
+
+        lines.push(code.to_string());
+    }
+
+    Ok(lines)
+}
+
+fn calculate_seat(code: String) -> u32 {
+    let (row, col) = code.split_at(7);
+
+    // calculate seat row
+    let mut upper: u32 = 127;
+    let mut lower: u32 = 0;
+    for s in row.chars() {
+        let a: u32 = upper - lower;
+        let b: u32 = a / 2;
+        if s == 'F' {
+            upper = lower + b;
+        } else if s == 'B' {
+            lower = lower + b + 1
+        } else {
+            panic!("unknown row character");
+        }
+    }
+    assert_eq!(lower, upper);
+
+    // calculate seat col
+    let mut left: u32 = 0;
+    let mut right: u32 = 7;
+    for s in col.chars() {
+        let a: u32 = right - left;
+        let b: u32 = a / 2;
+        if s == 'L' {
+            right = left + b;
+        } else if s == 'R' {
+            left = left + b + 1;
+        } else {
+            panic!("unknown col character");
+        }
+    }
+    assert_eq!(left, right);
+
+    lower * 8 + left
+}
+
+/// find_missing_seat finds the first gap in sorted seat ids, returning the
+/// seat just before the next taken one
+fn find_missing_seat(seat_ids: &[u32]) -> Option<u32> {
+    seat_ids
+        .windows(2)
+        .find(|pair| pair[1] != pair[0] + 1)
+        .map(|pair| pair[1] - 1)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_boarding_passes(content)
+    }
+
+    fn part_one(passes: &Self::Input) -> Result<Self::PartOne, Error> {
+        // part one
+        passes
+            .iter()
+            .cloned()
+            .map(calculate_seat)
+            .max()
+            .ok_or_else(|| Error::no_answer("there are no boarding passes"))
+    }
+
+    fn part_two(passes: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let mut seat_ids: Vec<u32> = passes.iter().cloned().map(calculate_seat).collect();
+
+        // part two
+        seat_ids.sort_unstable();
+        find_missing_seat(&seat_ids)
+            .ok_or_else(|| Error::no_answer("no seat is missing between two taken ones"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_calculate_seat() {
+        assert_eq!(super::calculate_seat(String::from("FBFBBFFRLR")), 357);
+        assert_eq!(super::calculate_seat(String::from("BFFFBBFRRR")), 567);
+        assert_eq!(super::calculate_seat(String::from("FFFBBBFRRR")), 119);
+        assert_eq!(super::calculate_seat(String::from("BBFFBBFRLL")), 820);
+    }
+
+    #[test]
+    fn test_no_answer() {
+        use super::Day05;
+        use common::Solution;
+
+        assert_eq!(super::find_missing_seat(&[3, 4, 6, 7]), Some(5));
+        assert_eq!(super::find_missing_seat(&[3, 4, 5]), None);
+        assert_eq!(super::find_missing_seat(&[]), None);
+
+        assert!(Day05::part_one(&vec![]).is_err());
+        assert!(Day05::part_two(&vec![]).is_err());
+        let passes = vec![String::from("FBFBBFFRLR"), String::from("FBFBBFFRRL")];
+        assert!(Day05::part_two(&passes).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use common::{input, Error, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+fn read_forms(content: &str) -> Result<Vec<Vec<String>>, Error> {
+    let forms: Vec<Vec<String>> = input::groups(content)
+        .iter()
+        .map(|group| group.iter().map(|(_, l)| l.to_string()).collect())
+        .collect();
+
+    Ok(forms)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<String>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_forms(content)
+    }
+
+    fn part_one(forms: &Self::Input) -> Result<Self::PartOne, Error> {
+        let mut questions: Vec<HashSet<char>> = vec![];
+        for group in forms {
+            let mut hs = HashSet::new();
+            for person in group {
+                for c in person.chars() {
+                    hs.insert(c);
+                }
+            }
+            questions.push(hs);
+        }
+
+        // flatmap nested vec/hashset and reduce/fold everything into an accumulator
+        // with each question counting as 1
+        let sum: u32 = questions
+            .iter()
+            .flat_map(|x| x.iter())
+            .fold(0, |acc, _| acc + 1);
+
+        Ok(sum)
+    }
+
+    fn part_two(forms: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // part two
+        let mut everyone_count = 0;
+        for group in forms {
+            // create mapping of group per question
+            let mut group_mapping = HashMap::new();
+            for person in group {
+                for question in person.chars() {
+                    *group_mapping.entry(question).or_insert(0) += 1;
+                }
+            }
+
+            // for every question of every group,
+            // if there are as many as the number of total group members
+            // then everyone has answered this question
+            for (_, value) in group_mapping {
+                if group.len() == value as usize {
+                    everyone_count += 1;
+                }
+            }
+        }
+
+        Ok(everyone_count)
+    }
+}
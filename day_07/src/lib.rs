@@ -1,38 +1,27 @@
+use common::{input, Error, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
-
-type RuleMapContains = HashMap<String, Vec<(String, u32)>>;
-type RuleMapBelongs = HashMap<String, Vec<String>>;
-
-fn parse_rules(filename: &str) -> (RuleMapContains, RuleMapBelongs) {
-    // open file
-    let path = Path::new(filename);
-    let path_display = path.display();
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("could not open {}: {}", path_display, why),
-        Ok(file) => file,
-    };
-
-    // read file contents into a string
-    let mut content = String::new();
-    if let Err(why) = file.read_to_string(&mut content) {
-        panic!("could not read {}: {}", path_display, why)
-    }
 
-    // transform into a vec of strings
-    let mut lines: Vec<&str> = content.split('\n').collect();
-    lines.pop(); // pop last empty line
+pub type RuleMapContains = HashMap<String, Vec<(String, u32)>>;
+pub type RuleMapBelongs = HashMap<String, Vec<String>>;
 
+fn parse_rules(content: &str) -> Result<(RuleMapContains, RuleMapBelongs), Error> {
     // transform into hashmaps
     let mut rule_map_contains: RuleMapContains = HashMap::new();
     let mut rule_map_belongs: RuleMapBelongs = HashMap::new();
-    for l in lines {
-        let parts: Vec<&str> = l.split(" bags contain ").collect();
-        let color = parts[0].to_string();
-        let rules = parts[1];
+    for (line_number, l) in input::lines(content) {
+        if l.trim().is_empty() {
+            continue;
+        }
+
+        let (color, rules) = match l.split_once(" bags contain ") {
+            Some(parts) => parts,
+            None => {
+                let message = "expected \"<color> bags contain <rules>\"";
+                return Err(Error::at(line_number, l, l, message));
+            }
+        };
+        let color = color.to_string();
 
         if rules == "no other bags." {
             rule_map_contains.insert(color, vec![]);
@@ -41,8 +30,19 @@ fn parse_rules(filename: &str) -> (RuleMapContains, RuleMapBelongs) {
 
         let rules = rules.split(", ").collect::<Vec<&str>>();
         for r in rules {
+            // "2 muted yellow bags." -> ["2", "muted", "yellow", "bags."]
             let r_parts = r.split(' ').collect::<Vec<&str>>();
-            let r_size: u32 = r_parts[0].parse().unwrap();
+            if r_parts.len() < 3 {
+                let message = format!("expected \"<count> <color> bags\", found {:?}", r);
+                return Err(Error::at(line_number, l, r, message));
+            }
+            let r_size: u32 = match r_parts[0].parse() {
+                Ok(size) => size,
+                Err(_) => {
+                    let message = format!("invalid bag count {:?}", r_parts[0]);
+                    return Err(Error::at(line_number, l, r_parts[0], message));
+                }
+            };
             let r_color = (r_parts[1].to_owned() + " " + r_parts[2]).to_string();
 
             // build rule_map_contains
@@ -60,7 +60,7 @@ fn parse_rules(filename: &str) -> (RuleMapContains, RuleMapBelongs) {
         }
     }
 
-    (rule_map_contains, rule_map_belongs)
+    Ok((rule_map_contains, rule_map_belongs))
 }
 
 fn process_shiny_gold_belonging(rule_map: &RuleMapBelongs) -> u32 {
@@ -68,8 +68,8 @@ fn process_shiny_gold_belonging(rule_map: &RuleMapBelongs) -> u32 {
     let mut queue: Vec<&str> = vec!["shiny gold"];
 
     // while there are things to process
-    while !queue.is_empty() {
-        let item = queue.pop().unwrap(); // take one item (color string)
+    while let Some(item) = queue.pop() {
+        // take one item (color string)
         if !rule_map.contains_key(item) {
             // if it doesn't belong to any other bag, continue to the next
             continue;
@@ -111,15 +111,26 @@ fn process_shiny_gold_count(rules: &RuleMapContains) -> u32 {
     get_count(rules, "shiny gold") - 1
 }
 
-fn main() {
-    let filename = String::from("./src/input");
-    let (rule_map_contains, rule_map_belongs) = parse_rules(&filename);
+pub struct Day07;
 
-    let result = process_shiny_gold_belonging(&rule_map_belongs);
-    println!("==result==");
-    println!("{}", result);
+impl Solution for Day07 {
+    type Input = (RuleMapContains, RuleMapBelongs);
+    type PartOne = u32;
+    type PartTwo = u32;
 
-    let result = process_shiny_gold_count(&rule_map_contains);
-    println!("==result part two==");
-    println!("{}", result);
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        parse_rules(content)
+    }
+
+    fn part_one(rules: &Self::Input) -> Result<Self::PartOne, Error> {
+        let (_, rule_map_belongs) = rules;
+
+        Ok(process_shiny_gold_belonging(rule_map_belongs))
+    }
+
+    fn part_two(rules: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let (rule_map_contains, _) = rules;
+
+        Ok(process_shiny_gold_count(rule_map_contains))
+    }
 }
@@ -0,0 +1,228 @@
+use common::{debug, input, trace, Error, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    cmd: String,
+    op: char,
+    val: u32,
+}
+
+fn parse_code(content: &str) -> Result<HashMap<u32, Instruction>, Error> {
+    // transform into hashmap
+    let mut code = HashMap::new();
+    for (line_number, line) in input::lines(content) {
+        // nop +0 -> ["nop", "+0"]
+        let (cmd, arg) = match line.split_once(' ') {
+            Some(parts) => parts,
+            None => {
+                let message = "expected \"<cmd> <+|-><value>\"";
+                return Err(Error::at(line_number, line, line, message));
+            }
+        };
+
+        let key = line_number as u32; // hashmap key is the line number
+        if cmd != "acc" && cmd != "jmp" && cmd != "nop" {
+            let message = format!("unknown cmd {:?}", cmd);
+            return Err(Error::at(line_number, line, cmd, message));
+        }
+        let cmd = cmd.to_string(); // eg. "nop"
+
+        // "+0" -> '+' and "0"
+        let mut arg_chars = arg.chars();
+        let op: char = match arg_chars.next() {
+            Some(op) if op == '+' || op == '-' => op,
+            _ => {
+                let message = format!("expected '+' or '-' sign, found {:?}", arg);
+                return Err(Error::at(line_number, line, arg, message));
+            }
+        };
+        let val = arg_chars.as_str();
+        let val = match val.parse() {
+            Ok(val) => val,
+            Err(_) => {
+                let message = format!("invalid value {:?}", val);
+                return Err(Error::at(line_number, line, val, message));
+            }
+        };
+        let instr = Instruction { cmd, op, val };
+        code.insert(key, instr);
+    }
+
+    Ok(code)
+}
+
+/// jump finds the line a jmp on line current goes to, failing when that is
+/// before the first line or past any line there can be
+fn jump(current: u32, instruction: &Instruction) -> Result<u32, Error> {
+    let target = match instruction.op {
+        '+' => current.checked_add(instruction.val),
+        _ => current
+            .checked_sub(instruction.val)
+            .filter(|&line| line > 0),
+    };
+
+    target.ok_or_else(|| {
+        let (current, val) = (i64::from(current), i64::from(instruction.val));
+        let line = if instruction.op == '+' {
+            current + val
+        } else {
+            current - val
+        };
+        let text = format!("{} {}{}", instruction.cmd, instruction.op, instruction.val);
+        let message = format!("jump to line {} is out of the program", line);
+        Error::at(current as usize, &text, &text[4..], message)
+    })
+}
+
+fn process_code(code: &HashMap<u32, Instruction>) -> Result<(i64, Vec<u32>, bool), Error> {
+    let mut acc: i64 = 0;
+    let mut current: u32 = 1;
+    let mut execution_set = HashSet::new();
+    let mut sequence: Vec<u32> = vec![];
+    let mut terminated = false;
+
+    loop {
+        if current - 1 == code.len().try_into().unwrap() {
+            debug!("terminated true, current: {}, acc: {}", current, acc);
+            terminated = true;
+            break;
+        }
+
+        if !code.contains_key(&current) {
+            debug!("line does not exist: {}, breaking", current);
+            break;
+        }
+
+        if execution_set.contains(&current) {
+            trace!("line already run: {} => {:?}", current, code[&current]);
+            break;
+        }
+
+        execution_set.insert(current);
+        sequence.push(current);
+        trace!("running now: {} => {:?}", current, code[&current]);
+        if code[&current].cmd == "acc" {
+            if code[&current].op == '+' {
+                acc += code[&current].val as i64;
+            } else if code[&current].op == '-' {
+                acc -= code[&current].val as i64;
+            } else {
+                panic!("unknown op: {}", code[&current].op);
+            }
+            current += 1;
+        } else if code[&current].cmd == "jmp" {
+            current = jump(current, &code[&current])?;
+        } else if code[&current].cmd == "nop" {
+            current += 1;
+        } else {
+            panic!("uknown cmd: {}", code[&current].cmd);
+        }
+    }
+
+    Ok((acc, sequence, terminated))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = HashMap<u32, Instruction>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        parse_code(content)
+    }
+
+    fn part_one(code: &Self::Input) -> Result<Self::PartOne, Error> {
+        trace!("code: {:?}", code);
+
+        // part one
+        let (acc_value, _, _) = process_code(code)?;
+        Ok(acc_value)
+    }
+
+    fn part_two(code: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let (_, sequence, _) = process_code(code)?;
+        trace!("sequence: {:?}", sequence);
+
+        // part two
+        for n in sequence.iter().rev() {
+            // check for every command we run
+            // to mangle it (if jmp -> nop, if nop -> jmp)
+            let mut mangled_code = code.clone();
+            if mangled_code[n].cmd == "jmp" {
+                mangled_code.get_mut(n).unwrap().cmd = "nop".to_string();
+            } else if mangled_code[n].cmd == "nop" {
+                mangled_code.get_mut(n).unwrap().cmd = "jmp".to_string();
+            } else {
+                continue;
+            }
+
+            debug!("mangling line {}", n);
+            // a swap that jumps out of the program does not fix it either
+            let (acc, _, terminated) = match process_code(&mangled_code) {
+                Ok(outcome) => outcome,
+                Err(_) => continue,
+            };
+            if terminated {
+                // if code terminates then we found it
+                return Ok(acc);
+            }
+        }
+
+        let message = "no single jmp or nop swap makes the program terminate";
+        Err(Error::no_answer(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_parse_code() {
+        let code = super::parse_code("nop +0\r\nacc +1\njmp -4").unwrap();
+        assert_eq!(code.len(), 3);
+        assert_eq!(code[&3].cmd, "jmp");
+        assert_eq!(code[&3].op, '-');
+        assert_eq!(code[&3].val, 4);
+
+        let error = super::parse_code("nop +0\nacc 1\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected '+' or '-' sign, found \"1\""
+        );
+
+        let error = super::parse_code("nop +0\nmul +2\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: unknown cmd \"mul\"");
+    }
+
+    #[test]
+    fn test_process_code() {
+        use super::Day08;
+        use common::Solution;
+
+        let code = super::parse_code("jmp -5\n").unwrap();
+        let error = Day08::part_one(&code).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: jump to line -4 is out of the program"
+        );
+        let code = super::parse_code("nop +0\njmp +1\njmp -3\n").unwrap();
+        let error = Day08::part_one(&code).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: jump to line 0 is out of the program"
+        );
+        let code = super::parse_code("acc +1\njmp +4294967295\n").unwrap();
+        assert!(Day08::part_one(&code).is_err());
+
+        // the swap of the nop would jump out, but that of the jmp ends it
+        let code = super::parse_code("nop -3\nacc +2\njmp -1\n").unwrap();
+        assert_eq!(Day08::part_one(&code).unwrap(), 2);
+        assert_eq!(Day08::part_two(&code).unwrap(), 2);
+        let code = super::parse_code("nop -3\njmp +0\njmp -1\n").unwrap();
+        assert!(Day08::part_two(&code).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use common::{input, Error, Solution};
+
+fn read_numbers(content: &str) -> Result<Vec<u64>, Error> {
+    input::numbers(content)
+}
+
+/// is_sum_batch returns true if a subgroup of the numbers that belong in batch
+/// are equal the sum given
+fn is_sum_batch(sum: u64, batch: &[u64]) -> bool {
+    for (outer_index, outer_value) in batch.iter().enumerate() {
+        let reference = outer_value;
+        for (inner_index, inner_value) in batch.iter().enumerate() {
+            if inner_index < outer_index {
+                // those have already been checked
+                continue;
+            }
+            if reference == inner_value {
+                continue;
+            }
+            if reference + inner_value == sum {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_invalid_sum(numbers: &[u64]) -> Result<u64, Error> {
+    let batch_size = 25;
+    let mut invalid_sum = None;
+    for (index, n) in numbers.iter().enumerate() {
+        if index < batch_size {
+            // ignore the first 5 or 25 since they are the preable
+            continue;
+        }
+        let prev_batch = &numbers[index - batch_size..index];
+        if !is_sum_batch(*n, prev_batch) {
+            invalid_sum = Some(*n);
+        }
+    }
+
+    invalid_sum.ok_or_else(|| {
+        let message = "no number after the first 25 is missing a pair adding up to it";
+        Error::no_answer(message)
+    })
+}
+
+/// find_contiguous_range finds the first run of at least two numbers adding
+/// up to sum
+fn find_contiguous_range(numbers: &[u64], sum: u64) -> Option<&[u64]> {
+    for start in 0..numbers.len() {
+        let mut running_sum: u64 = 0;
+        for (end, &n) in numbers.iter().enumerate().skip(start) {
+            running_sum += n;
+            if end > start && running_sum == sum {
+                return Some(&numbers[start..=end]);
+            } else if running_sum > sum {
+                // running_sum is over our target number, try the next start
+                break;
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_numbers(content)
+    }
+
+    fn part_one(numbers: &Self::Input) -> Result<Self::PartOne, Error> {
+        // part one
+        find_invalid_sum(numbers)
+    }
+
+    fn part_two(numbers: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let invalid_sum = find_invalid_sum(numbers)?;
+
+        // part two
+        let range = find_contiguous_range(numbers, invalid_sum).ok_or_else(|| {
+            let message = format!("no contiguous numbers add up to {}", invalid_sum);
+            Error::no_answer(message)
+        })?;
+
+        // add up smallest and larger
+        let smallest = range.iter().min().copied().unwrap_or(0);
+        let largest = range.iter().max().copied().unwrap_or(0);
+        Ok(smallest + largest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_is_sum_batch() {
+        assert!(super::is_sum_batch(40, &[35, 20, 15, 25, 47]));
+        assert!(super::is_sum_batch(62, &[20, 15, 25, 47, 40]));
+        assert!(super::is_sum_batch(55, &[15, 25, 47, 40, 62]));
+        assert!(!super::is_sum_batch(50, &[15, 25, 47, 40, 62]));
+    }
+
+    #[test]
+    fn test_find_contiguous_range() {
+        let numbers = [35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182];
+        assert_eq!(
+            super::find_contiguous_range(&numbers, 127),
+            Some(&[15, 25, 47, 40][..])
+        );
+        // a number on its own is not a range
+        assert_eq!(super::find_contiguous_range(&numbers, 47), None);
+        assert_eq!(super::find_contiguous_range(&numbers, 1), None);
+        assert_eq!(super::find_contiguous_range(&[], 1), None);
+
+        assert!(super::find_invalid_sum(&numbers).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use common::{input, Error, Solution};
+use std::collections::HashMap;
+
+fn read_numbers(content: &str) -> Result<Vec<u64>, Error> {
+    input::numbers(content)
+}
+
+/// sort_adapters orders the adapters and adds the built-in one, failing
+/// when two of them are too far apart to be chained
+fn sort_adapters(numbers: &[u64]) -> Result<Vec<u64>, Error> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let highest = sorted
+        .last()
+        .ok_or_else(|| Error::no_answer("there are no adapters"))?;
+    sorted.push(highest + 3); // add built-in adapter joltage
+
+    let mut prev = 0;
+    for &n in &sorted {
+        if !(1..=3).contains(&(n - prev)) {
+            let message = format!("no adapter takes {} jolts to give {}", prev, n);
+            return Err(Error::no_answer(message));
+        }
+        prev = n;
+    }
+
+    Ok(sorted)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_numbers(content)
+    }
+
+    fn part_one(numbers: &Self::Input) -> Result<Self::PartOne, Error> {
+        // part one
+        let mut differences = HashMap::new();
+        let sorted = sort_adapters(numbers)?;
+        let mut prev = 0;
+        for n in &sorted {
+            *differences.entry(n - prev).or_insert(0) += 1;
+            prev = *n;
+        }
+
+        let count = |difference| differences.get(&difference).copied().unwrap_or(0);
+        Ok(count(1) * count(3))
+    }
+
+    fn part_two(numbers: &Self::Input) -> Result<Self::PartTwo, Error> {
+        let sorted = sort_adapters(numbers)?;
+
+        // part two
+        // calculate all possible combinations by counting how many consecutive 1s
+        let mut product: u64 = 1;
+        let mut prev = 0;
+        let mut consecutive = 0;
+        for n in &sorted {
+            if n - prev == 1 {
+                consecutive += 1;
+            } else {
+                // depending on the consecutive 1s, multiply final product by
+                // all the possible combinations. Differences of 2 and more
+                // than 4 consecutive 1s are not accounted for.
+                if n - prev == 2 || consecutive > 4 {
+                    let message = "arrangements are only counted for differences of 1 and 3, \
+                                   with at most 4 differences of 1 in a row";
+                    return Err(Error::no_answer(message));
+                } else if consecutive == 4 {
+                    product *= 7;
+                } else if consecutive == 3 {
+                    product *= 4;
+                } else if consecutive == 2 {
+                    product *= 2;
+                }
+
+                consecutive = 0;
+            }
+            prev = *n;
+        }
+
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use common::Solution;
+
+    #[test]
+    fn test_parts() {
+        let numbers = super::read_numbers(include_str!("input_example")).unwrap();
+        assert_eq!(Day10::part_one(&numbers).unwrap(), 7 * 5);
+        assert_eq!(Day10::part_two(&numbers).unwrap(), 8);
+
+        // differences of 2 are allowed, but not counted in part two
+        assert_eq!(Day10::part_one(&vec![2, 3, 5]).unwrap(), 1);
+        assert!(Day10::part_two(&vec![2, 3, 5]).is_err());
+        assert!(Day10::part_two(&vec![1, 2, 3, 4, 5]).is_err());
+        assert_eq!(Day10::part_one(&vec![3, 6]).unwrap(), 0);
+
+        assert!(Day10::part_one(&vec![]).is_err());
+        assert!(Day10::part_two(&vec![1, 5]).is_err());
+        assert!(Day10::part_one(&vec![1, 1]).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+use common::grid::ADJACENT;
+use common::{debug, trace, Error, Grid, Solution};
+
+fn render_seats(seats: &Grid<char>) -> String {
+    seats.to_string()
+}
+
+fn read_seats(content: &str) -> Result<Grid<char>, Error> {
+    // load the seats into a grid of characters
+    Grid::parse(content, |c| match c {
+        'L' | '#' | '.' => Some(c),
+        _ => None,
+    })
+}
+
+fn get_evolved_seat(seats: &Grid<char>, row: usize, col: usize) -> char {
+    let seat = seats[(row, col)];
+    let occupied_count = seats
+        .neighbours8(row, col)
+        .filter(|&(_, &neighbour)| neighbour == '#')
+        .count();
+
+    trace!(
+        "occupied neighbours of ({}, {}): {}",
+        row,
+        col,
+        occupied_count
+    );
+
+    if seat == 'L' && occupied_count == 0 {
+        return '#';
+    } else if seat == '#' && occupied_count >= 4 {
+        return 'L';
+    }
+
+    seat
+}
+
+fn evolve(seats: &Grid<char>) -> Grid<char> {
+    let mut evolved = seats.clone();
+    for (row, col) in seats.positions() {
+        evolved[(row, col)] = get_evolved_seat(seats, row, col);
+    }
+
+    evolved
+}
+
+fn count_occupied(seats: &Grid<char>) -> u32 {
+    seats.iter().filter(|&&seat| seat == '#').count() as u32
+}
+
+fn evolve_b(seats: &Grid<char>) -> Grid<char> {
+    let mut evolved = seats.clone();
+    for (row, col) in seats.positions() {
+        evolved[(row, col)] = get_evolved_seat_b(seats, row, col);
+    }
+
+    evolved
+}
+
+fn get_evolved_seat_b(seats: &Grid<char>, row: usize, col: usize) -> char {
+    let seat = seats[(row, col)];
+    let occupied_count = get_visible_count(seats, row, col);
+
+    if seat == 'L' && occupied_count == 0 {
+        return '#';
+    } else if seat == '#' && occupied_count >= 5 {
+        return 'L';
+    }
+
+    seat
+}
+
+fn get_visible_count(seats: &Grid<char>, row: usize, col: usize) -> u32 {
+    let mut visible_count = 0;
+
+    for &direction in &ADJACENT {
+        // the first seat in sight, skipping the floor
+        let visible = seats
+            .ray(row, col, direction)
+            .map(|(_, &seat)| seat)
+            .find(|&seat| seat != '.');
+        if visible == Some('#') {
+            visible_count += 1;
+        }
+    }
+
+    visible_count
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(content: &str) -> Result<Self::Input, Error> {
+        read_seats(content)
+    }
+
+    fn part_one(seats: &Self::Input) -> Result<Self::PartOne, Error> {
+        debug!("seats:\n{}", render_seats(seats));
+
+        // part one
+        let mut prev_iteration = seats.clone();
+        let mut evolved = evolve(&prev_iteration);
+        let mut count_iterations = 1;
+        while evolved != prev_iteration {
+            prev_iteration = evolved;
+            evolved = evolve(&prev_iteration);
+            count_iterations += 1;
+            trace!(
+                "generation {}:\n{}",
+                count_iterations,
+                render_seats(&evolved)
+            );
+        }
+        debug!("count_iterations: {}", count_iterations);
+
+        Ok(count_occupied(&evolved))
+    }
+
+    fn part_two(seats: &Self::Input) -> Result<Self::PartTwo, Error> {
+        // part two
+        let mut prev_iteration = seats.clone();
+        let mut evolved = evolve_b(seats);
+        let mut count_iterations = 1;
+        while evolved != prev_iteration {
+            prev_iteration = evolved;
+            evolved = evolve_b(&prev_iteration);
+            count_iterations += 1;
+            trace!(
+                "generation {}:\n{}",
+                count_iterations,
+                render_seats(&evolved)
+            );
+        }
+        debug!("count_iterations: {}", count_iterations);
+
+        Ok(count_occupied(&evolved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Grid;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        super::read_seats(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_evolve_first_iteration() {
+        let seats = grid(&[
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        let expected = grid(&[
+            "#.##.##.##",
+            "#######.##",
+            "#.#.#..#..",
+            "####.##.##",
+            "#.##.##.##",
+            "#.#####.##",
+            "..#.#.....",
+            "##########",
+            "#.######.#",
+            "#.#####.##",
+        ]);
+
+        assert_eq!(evolved, expected);
+    }
+
+    #[test]
+    fn test_evolve_second_iteration() {
+        let seats = grid(&[
+            "#.##.##.##",
+            "#######.##",
+            "#.#.#..#..",
+            "####.##.##",
+            "#.##.##.##",
+            "#.#####.##",
+            "..#.#.....",
+            "##########",
+            "#.######.#",
+            "#.#####.##",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        let expected = grid(&[
+            "#.LL.L#.##",
+            "#LLLLLL.L#",
+            "L.L.L..L..",
+            "#LLL.LL.L#",
+            "#.LL.LL.LL",
+            "#.LLLL#.##",
+            "..L.L.....",
+            "#LLLLLLLL#",
+            "#.LLLLLL.L",
+            "#.#LLLL.##",
+        ]);
+
+        assert_eq!(evolved, expected);
+    }
+
+    #[test]
+    fn test_evolve_third_iteration() {
+        let seats = grid(&[
+            "#.LL.L#.##",
+            "#LLLLLL.L#",
+            "L.L.L..L..",
+            "#LLL.LL.L#",
+            "#.LL.LL.LL",
+            "#.LLLL#.##",
+            "..L.L.....",
+            "#LLLLLLLL#",
+            "#.LLLLLL.L",
+            "#.#LLLL.##",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        let expected = grid(&[
+            "#.##.L#.##",
+            "#L###LL.L#",
+            "L.#.#..#..",
+            "#L##.##.L#",
+            "#.##.LL.LL",
+            "#.###L#.##",
+            "..#.#.....",
+            "#L######L#",
+            "#.LL###L.L",
+            "#.#L###.##",
+        ]);
+
+        assert_eq!(evolved, expected);
+    }
+
+    #[test]
+    fn test_evolve_fourth_iteration() {
+        let seats = grid(&[
+            "#.##.L#.##",
+            "#L###LL.L#",
+            "L.#.#..#..",
+            "#L##.##.L#",
+            "#.##.LL.LL",
+            "#.###L#.##",
+            "..#.#.....",
+            "#L######L#",
+            "#.LL###L.L",
+            "#.#L###.##",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        let expected = grid(&[
+            "#.#L.L#.##",
+            "#LLL#LL.L#",
+            "L.L.L..#..",
+            "#LLL.##.L#",
+            "#.LL.LL.LL",
+            "#.LL#L#.##",
+            "..L.L.....",
+            "#L#LLLL#L#",
+            "#.LLLLLL.L",
+            "#.#L#L#.##",
+        ]);
+
+        assert_eq!(evolved, expected);
+    }
+
+    #[test]
+    fn test_evolve_fifth_iteration() {
+        let seats = grid(&[
+            "#.#L.L#.##",
+            "#LLL#LL.L#",
+            "L.L.L..#..",
+            "#LLL.##.L#",
+            "#.LL.LL.LL",
+            "#.LL#L#.##",
+            "..L.L.....",
+            "#L#LLLL#L#",
+            "#.LLLLLL.L",
+            "#.#L#L#.##",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        let expected = grid(&[
+            "#.#L.L#.##",
+            "#LLL#LL.L#",
+            "L.#.L..#..",
+            "#L##.##.L#",
+            "#.#L.LL.LL",
+            "#.#L#L#.##",
+            "..L.L.....",
+            "#L#L##L#L#",
+            "#.LLLLLL.L",
+            "#.#L#L#.##",
+        ]);
+
+        assert_eq!(evolved, expected);
+    }
+
+    #[test]
+    fn test_evolve_six_iteration() {
+        let seats = grid(&[
+            "#.#L.L#.##",
+            "#LLL#LL.L#",
+            "L.#.L..#..",
+            "#L##.##.L#",
+            "#.#L.LL.LL",
+            "#.#L#L#.##",
+            "..L.L.....",
+            "#L#L##L#L#",
+            "#.LLLLLL.L",
+            "#.#L#L#.##",
+        ]);
+        let evolved = super::evolve(&seats);
+
+        assert_eq!(evolved, seats);
+    }
+
+    #[test]
+    fn test_get_visible_count() {
+        let seats = grid(&[
+            ".......#.",
+            "...#.....",
+            ".#.......",
+            ".........",
+            "..#L....#",
+            "....#....",
+            ".........",
+            "#........",
+            "...#.....",
+        ]);
+
+        assert_eq!(super::get_visible_count(&seats, 4, 3), 8);
+    }
+
+    #[test]
+    fn test_get_visible_count_small() {
+        let seats = grid(&[".............", ".L.L.#.#.#.#.", "............."]);
+
+        assert_eq!(super::get_visible_count(&seats, 2, 2), 0);
+    }
+}